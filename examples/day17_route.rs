@@ -0,0 +1,40 @@
+//! Saves a picture of the route of the crucible through the heat-loss map of day 17.
+//!
+//! Usage: cargo run --example day17_route [--features png] -- [--ultra] [output path]
+//! The picture is written to day17.ppm by default, or as PNG when the path ends in .png.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use advent_of_code_2023::days::{self, day17};
+use advent_of_code_2023::input::Source;
+use advent_of_code_2023::solution::Solution;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let ultra = args.first().is_some_and(|arg| arg == "--ultra");
+    if ultra {
+        args.remove(0);
+    }
+    let path = PathBuf::from(args.first().map_or("day17.ppm", String::as_str));
+
+    let day = days::get(17).unwrap();
+    let grid = match Source::File(day.input_path()).load(day) {
+        Ok(lines) => day17::Day17::parse(&lines).map_err(|error| format!("Invalid input: {}", error)),
+        Err(error) => Err(error.to_string()),
+    };
+    let grid = match grid {
+        Ok(grid) => grid,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Large enough to see single blocks
+    if let Err(error) = day17::draw_route(&grid, ultra).scaled(4).save(&path) {
+        eprintln!("Could not save {}: {}", path.display(), error);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use crate::days::Day;
+use crate::error::{parse_number, ParseError};
+use crate::input::Source;
+use crate::solution::{Answer, Part};
+
+/// Registry of known-correct answers, checked by `aoc verify`
+pub const ANSWERS_PATH: &str = "answers.txt";
+
+/// Name of the real puzzle input in the registry, any other name refers to an example
+pub const REAL_INPUT: &str = "input";
+
+/// Known answer of one part of a day, for either the real input or one of the examples
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+impl Expected {
+    pub fn source(&self, day: &Day) -> Source {
+        if self.input == REAL_INPUT {
+            Source::File(day.input_path())
+        } else {
+            Source::Example(self.input.clone())
+        }
+    }
+}
+
+/// Result of solving one registry entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { answer: String },
+    Error(String),
+}
+
+// Splits off the first whitespace separated field of `text`
+fn field<'a>(index: usize, line: &'a str, text: &'a str, expected: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let text = text.trim_start();
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    if end == 0 {
+        return Err(ParseError::end_of_line(index, line, format!("expected {}", expected)));
+    }
+    Ok((&text[..end], &text[end..]))
+}
+
+/// Parses lines of `<day> <input> <part> <answer>`, where the answer is the rest of the line.
+/// Empty lines and lines starting with '#' are ignored.
+pub fn parse(lines: &[String]) -> Result<Vec<Expected>, ParseError> {
+    let mut entries = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (day, rest) = field(index, line, line, "day")?;
+        let (input, rest) = field(index, line, rest, "input name")?;
+        let (part, rest) = field(index, line, rest, "part")?;
+        let answer = rest.trim();
+        if answer.is_empty() {
+            return Err(ParseError::end_of_line(index, line, "expected answer"));
+        }
+
+        let number = parse_number::<u8>(index, line, day)?;
+        if crate::days::get(number).is_none() {
+            return Err(ParseError::at(index, line, day, "unknown day"));
+        }
+        let part = parse_number::<u8>(index, line, part)
+            .and_then(|number| Part::try_from(number).map_err(|_| ParseError::at(index, line, part, "expected part 1 or 2")))?;
+
+        entries.push(Expected {
+            day: number,
+            input: input.to_owned(),
+            part,
+            answer: answer.to_owned(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Reads and parses the registry at `path`
+pub fn load(path: &Path) -> Result<Vec<Expected>, String> {
+    let lines = crate::input::read_file(path).map_err(|error| error.to_string())?;
+    parse(&lines).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+/// Solves the part of `expected` on the already loaded `lines` and compares the answers
+pub fn check(day: &Day, lines: &[String], expected: &Expected) -> Outcome {
+    match day.solve(lines, Some(expected.part)) {
+        Ok(answers) => match answers.into_iter().next() {
+            Some(Answer { value: Ok(value), .. }) if value == expected.answer => Outcome::Pass,
+            Some(Answer { value: Ok(answer), .. }) => Outcome::Fail { answer },
+            Some(Answer { value: Err(reason), .. }) => Outcome::Error(format!("No answer: {}", reason)),
+            None => Outcome::Error("no answer".to_string()),
+        },
+        Err(error) => Outcome::Error(format!("Invalid input: {}", error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::split_lines;
+
+    #[test]
+    fn test_parse() {
+        let lines = split_lines("# day input part answer\n\n1  input 1 53651\n25 example 2 Merry Christmas!");
+        let entries = parse(&lines).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].day, entries[0].part, entries[0].answer.as_str()), (1, Part::One, "53651"));
+        assert_eq!((entries[1].input.as_str(), entries[1].answer.as_str()), ("example", "Merry Christmas!"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse(&split_lines("1 input 3 42")).unwrap_err();
+        assert_eq!((error.column, error.message.as_str()), (9, "expected part 1 or 2"));
+        let error = parse(&split_lines("26 input 1 42")).unwrap_err();
+        assert_eq!(error.message, "unknown day");
+        let error = parse(&split_lines("1 input 1")).unwrap_err();
+        assert_eq!(error.message, "expected answer");
+    }
+
+    #[test]
+    fn test_check() {
+        let day = crate::days::get(15).unwrap();
+        let lines = split_lines(crate::days::day15::EXAMPLE);
+        let mut expected = Expected {
+            day: 15,
+            input: "example".to_string(),
+            part: Part::One,
+            answer: "1320".to_string(),
+        };
+        assert_eq!(check(day, &lines, &expected), Outcome::Pass);
+        expected.answer = "1321".to_string();
+        assert_eq!(check(day, &lines, &expected), Outcome::Fail { answer: "1320".to_string() });
+    }
+}
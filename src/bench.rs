@@ -0,0 +1,127 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::solution::{Part, Solution};
+
+/// Timed phase of a solution
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Durations of every iteration of each phase, parts that were not run have no samples
+#[derive(Debug, Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn phases(&self) -> [(Phase, &[Duration]); 3] {
+        [
+            (Phase::Parse, &self.parse),
+            (Phase::Part1, &self.part1),
+            (Phase::Part2, &self.part2),
+        ]
+    }
+}
+
+/// Type-erased benchmark of a day, see `Runner`
+pub type Bench = fn(&[String], Option<Part>, usize) -> Result<Timings, ParseError>;
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+/// Times parsing and the requested part, or both parts if `part` is `None`, over `iterations` runs
+pub fn measure<S: Solution>(lines: &[String], part: Option<Part>, iterations: usize) -> Result<Timings, ParseError> {
+    let mut timings = Timings::default();
+
+    for _ in 0..iterations {
+        let (input, elapsed) = time(|| S::parse(black_box(lines)));
+        let input = input?;
+        timings.parse.push(elapsed);
+
+        if part != Some(Part::Two) {
+            timings.part1.push(time(|| S::part1(&input)).1);
+        }
+        if part != Some(Part::One) {
+            timings.part2.push(time(|| S::part2(&input)).1);
+        }
+    }
+
+    Ok(timings)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub total: Duration,
+}
+
+impl Stats {
+    /// Statistics of the samples, `None` if there are none.
+    /// The median of an even number of samples is the mean of the middle two.
+    pub fn of(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[n - 1],
+            total: sorted.iter().sum(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::of(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(stats.total, Duration::from_millis(11));
+
+        assert_eq!(Stats::of(&samples[..3]).unwrap().median, Duration::from_millis(3));
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        let lines = crate::input::split_lines(crate::days::day15::EXAMPLE);
+        let timings = measure::<crate::days::day15::Day15>(&lines, Some(Part::Two), 3).unwrap();
+        assert_eq!((timings.parse.len(), timings.part1.len(), timings.part2.len()), (3, 0, 3));
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use advent_of_code_2023::answers::{self, Expected, Outcome};
+use advent_of_code_2023::bench::Stats;
+use advent_of_code_2023::days::{self, Day};
+use advent_of_code_2023::input::Source;
+use advent_of_code_2023::output::{self, Format, Record, Value};
+use advent_of_code_2023::solution::Part;
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part <1|2>] [--input <path> | --stdin | --example <name>] [--format <format>]
+       aoc bench <day|all> [--iterations <n>] [options of run]
+       aoc verify [day|all]
+
+By default the input of each day is read from inputs/<day>.txt
+--format is one of text (the default), json, csv or tsv. Other than text, every result is written to
+stdout as a record, with diagnostics on stderr. json writes one object per line.
+`bench` times parsing and each part separately, over 10 iterations by default
+`verify` checks the answers of the real inputs and examples against answers.txt
+
+Examples:
+    aoc run 17
+    aoc run all
+    aoc run 5 --part 2
+    aoc run 8 --example example_3
+    cat my_input.txt | aoc run 12 --stdin
+    aoc run all --format json
+    aoc bench all --iterations 5 --format csv
+    aoc verify";
+
+enum Command {
+    Run(Args),
+    Bench(Args, BenchOptions),
+    Verify(Vec<&'static Day>),
+}
+
+struct BenchOptions {
+    iterations: usize,
+}
+
+struct Args {
+    days: Vec<&'static Day>,
+    part: Option<Part>,
+    source: Option<Source>,
+    format: Format,
+}
+
+fn parse_days(day: Option<&str>) -> Result<Vec<&'static Day>, String> {
+    match day {
+        Some("all") => Ok(days::DAYS.iter().collect()),
+        Some(day) => {
+            let number = day.parse::<u8>().map_err(|_| format!("Invalid day: {}", day))?;
+            Ok(vec![days::get(number).ok_or(format!("Day {} is not solved", number))?])
+        }
+        None => Err("Missing day".to_string()),
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    let bench = match args.next().map(String::as_str) {
+        Some("run") => false,
+        Some("bench") => true,
+        Some("verify") => {
+            let days = parse_days(Some(args.next().map_or("all", String::as_str)))?;
+            if let Some(arg) = args.next() {
+                return Err(format!("Unknown argument: {}", arg));
+            }
+            return Ok(Command::Verify(days));
+        }
+        Some(command) => return Err(format!("Unknown command: {}", command)),
+        None => return Err("Missing command".to_string()),
+    };
+
+    let days = parse_days(args.next().map(String::as_str))?;
+
+    let mut part = None;
+    let mut source = None;
+    let mut format = Format::Text;
+    let mut options = BenchOptions { iterations: 10 };
+    while let Some(arg) = args.next() {
+        let new_source = match arg.as_str() {
+            "--part" => {
+                let value = args.next().ok_or("Missing value for --part")?;
+                let number = value.parse::<u8>().map_err(|_| format!("Invalid part: {}", value))?;
+                part = Some(Part::try_from(number)?);
+                continue;
+            }
+            "--iterations" if bench => {
+                let value = args.next().ok_or("Missing value for --iterations")?;
+                options.iterations = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or(format!("Invalid number of iterations: {}", value))?;
+                continue;
+            }
+            "--format" => {
+                format = args.next().ok_or("Missing value for --format")?.parse()?;
+                continue;
+            }
+            "--input" => Source::File(PathBuf::from(args.next().ok_or("Missing value for --input")?)),
+            "--stdin" => Source::Stdin,
+            "--example" => Source::Example(args.next().ok_or("Missing value for --example")?.clone()),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        };
+
+        if source.is_some() {
+            return Err("Only one of --input, --stdin and --example can be given".to_string());
+        }
+        if days.len() > 1 && !matches!(new_source, Source::Example(_)) {
+            return Err(format!("{} can only be used with a single day", arg));
+        }
+        source = Some(new_source);
+    }
+
+    let args = Args { days, part, source, format };
+    if bench {
+        Ok(Command::Bench(args, options))
+    } else {
+        Ok(Command::Run(args))
+    }
+}
+
+// Reads the input of the day from the given source, or from its default file
+fn load(day: &Day, source: &Option<Source>) -> Option<Vec<String>> {
+    let source = source.clone().unwrap_or(Source::File(day.input_path()));
+    match source.load(day) {
+        Ok(lines) => Some(lines),
+        Err(error) => {
+            eprintln!("{}", error);
+            None
+        }
+    }
+}
+
+fn run(args: Args) -> ExitCode {
+    let mut records = Vec::new();
+    for day in args.days {
+        let Some(lines) = load(day, &args.source) else {
+            return ExitCode::FAILURE;
+        };
+
+        let answers = match day.solve(&lines, args.part) {
+            Ok(answers) => answers,
+            Err(error) => {
+                eprintln!("Invalid input: {}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        if args.format == Format::Text {
+            println!("Day {}", day.number);
+            for answer in answers {
+                match answer.value {
+                    Ok(value) => println!("  Part {}: {}", answer.part.number(), value),
+                    Err(reason) => println!("  Part {}: no answer, {}", answer.part.number(), reason),
+                }
+            }
+            continue;
+        }
+
+        let input_hash = output::input_hash(&lines);
+        for answer in answers {
+            // Parts without an answer have no record
+            let value = match answer.value {
+                Ok(value) => value,
+                Err(reason) => {
+                    eprintln!("Day {} part {}: no answer, {}", day.number, answer.part.number(), reason);
+                    continue;
+                }
+            };
+            records.push(vec![
+                ("day", Value::Number(day.number.into())),
+                ("part", Value::Number(answer.part.number().into())),
+                ("answer", Value::Text(value)),
+                ("elapsed_ns", Value::Number(answer.elapsed.as_nanos())),
+                ("input_hash", Value::Text(input_hash.clone())),
+            ]);
+        }
+    }
+
+    if args.format != Format::Text {
+        print!("{}", output::format_records(args.format, &records));
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(args: Args, options: BenchOptions) -> ExitCode {
+    // Statistics of every phase that was run, in order
+    let mut results = Vec::new();
+    for day in args.days {
+        let Some(lines) = load(day, &args.source) else {
+            return ExitCode::FAILURE;
+        };
+
+        let timings = match day.measure(&lines, args.part, options.iterations) {
+            Ok(timings) => timings,
+            Err(error) => {
+                eprintln!("Invalid input: {}", error);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        for (phase, samples) in timings.phases() {
+            if let Some(stats) = Stats::of(samples) {
+                results.push((day.number, phase, stats));
+            }
+        }
+    }
+
+    let iterations = options.iterations;
+    if args.format == Format::Text {
+        println!("{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}", "Day", "Phase", "Min", "Median", "Max", "Total");
+        for (day, phase, stats) in &results {
+            println!(
+                "{:>3}  {:<6}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+                day, phase.to_string(), stats.min, stats.median, stats.max, stats.total
+            );
+        }
+        let median = results.iter().map(|(_, _, stats)| stats.median).sum::<Duration>();
+        let total = results.iter().map(|(_, _, stats)| stats.total).sum::<Duration>();
+        println!("Sum of medians: {:.2?}, total of {} iterations: {:.2?}", median, iterations, total);
+        return ExitCode::SUCCESS;
+    }
+
+    let records = results
+        .into_iter()
+        .map(|(day, phase, stats)| -> Record {
+            vec![
+                ("day", Value::Number(day.into())),
+                ("phase", Value::Text(phase.to_string())),
+                ("iterations", Value::Number(iterations as u128)),
+                ("min_ns", Value::Number(stats.min.as_nanos())),
+                ("median_ns", Value::Number(stats.median.as_nanos())),
+                ("max_ns", Value::Number(stats.max.as_nanos())),
+                ("total_ns", Value::Number(stats.total.as_nanos())),
+            ]
+        })
+        .collect::<Vec<_>>();
+    print!("{}", output::format_records(args.format, &records));
+    ExitCode::SUCCESS
+}
+
+// Outcome of a part as shown in the table, "-" if the registry has no answer for it
+fn status(outcome: Option<&Outcome>) -> &'static str {
+    match outcome {
+        None => "-",
+        Some(Outcome::Pass) => "pass",
+        Some(Outcome::Fail { .. }) => "FAIL",
+        Some(Outcome::Error(_)) => "ERROR",
+    }
+}
+
+fn verify(days: Vec<&'static Day>) -> ExitCode {
+    let expected = match answers::load(Path::new(answers::ANSWERS_PATH)) {
+        Ok(expected) => expected,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    println!("{:>3}  {:<10}  {:<6}  Part 2", "Day", "Input", "Part 1");
+    for day in days {
+        // Inputs in the order they first appear in the registry
+        let entries = expected.iter().filter(|e| e.day == day.number).collect::<Vec<_>>();
+        let mut seen = HashSet::new();
+        let inputs = entries.iter().map(|e| e.input.as_str()).filter(|input| seen.insert(*input)).collect::<Vec<_>>();
+
+        for input in inputs {
+            let entries = entries.iter().filter(|e| e.input == input).copied().collect::<Vec<&Expected>>();
+            let outcomes = match entries[0].source(day).load(day) {
+                Ok(lines) => entries.iter().map(|e| answers::check(day, &lines, e)).collect::<Vec<_>>(),
+                Err(error) => vec![Outcome::Error(error.to_string()); entries.len()],
+            };
+
+            let outcome_of = |part| entries.iter().position(|e| e.part == part).map(|i| &outcomes[i]);
+            println!(
+                "{:>3}  {:<10}  {:<6}  {}",
+                day.number,
+                input,
+                status(outcome_of(Part::One)),
+                status(outcome_of(Part::Two))
+            );
+
+            checked += entries.len();
+            for (entry, outcome) in entries.into_iter().zip(outcomes) {
+                if outcome != Outcome::Pass {
+                    failures.push((entry, outcome));
+                }
+            }
+        }
+    }
+
+    for (entry, outcome) in &failures {
+        let location = format!("Day {} {} part {}", entry.day, entry.input, entry.part.number());
+        match outcome {
+            Outcome::Fail { answer } => eprintln!("{}: expected {}, got {}", location, entry.answer, answer),
+            Outcome::Error(error) => eprintln!("{}: {}", location, error),
+            Outcome::Pass => (),
+        }
+    }
+    println!("{} of {} answers correct", checked - failures.len(), checked);
+
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match parse_args(&args) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Bench(args, options)) => bench(args, options),
+        Ok(Command::Verify(days)) => verify(days),
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
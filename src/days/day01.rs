@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use advent_of_code_2023::read_lines;
+use crate::error::ParseError;
+use crate::solution::Solution;
 
 fn calculate_sum<'a, T: Iterator<Item = &'a String>>(lines: T) -> u32 {
     let mut sum = 0;
@@ -49,7 +50,7 @@ fn calculate_sum_with_words<'a, T: Iterator<Item = &'a String>>(lines: T) -> u32
 
     let mut sum = 0;
     for line in lines {
-        let mut first_index = std::usize::MAX;
+        let mut first_index = usize::MAX;
         let mut last_index = 0;
         let mut first_digit = 0;
         let mut last_digit = 0;
@@ -74,28 +75,71 @@ fn calculate_sum_with_words<'a, T: Iterator<Item = &'a String>>(lines: T) -> u32
     sum
 }
 
-fn main() {
-    let lines = read_lines("inputs/1.txt").unwrap();
+// Example for part 2, part 1 only considers the digits
+pub const EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+1oneightq
+4kok
+twor934onetwo";
 
-    let _lines: Vec<String> = lines.flatten().collect();
+pub const EXAMPLE_2: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
 
-    let sum = calculate_sum(_lines.iter());
+pub const EXAMPLE_3: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
 
-    let _example = vec![
-        "two1nine".to_string(),
-        "eightwothree".to_string(),
-        "abcone2threexyz".to_string(),
-        "xtwone3four".to_string(),
-        "4nineeightseven2".to_string(),
-        "zoneight234".to_string(),
-        "7pqrstsixteen".to_string(),
-        "1oneightq".to_string(),
-        "4kok".to_string(),
-        "twor934onetwo".to_string(),
-    ];
+pub struct Day01;
 
-    let sum_words = calculate_sum_with_words(_lines.iter());
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    println!("{}", sum);
-    println!("{}", sum_words);
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE), ("example_2", EXAMPLE_2), ("example_3", EXAMPLE_3)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok(lines.to_vec())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        calculate_sum(input.iter())
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        calculate_sum_with_words(input.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day01>(EXAMPLE);
+        assert_eq!(Day01::part1(&input), 358);
+        assert_eq!(Day01::part2(&input), 365);
+    }
+
+    #[test]
+    fn test_published_examples() {
+        assert_eq!(Day01::part1(&parse_example::<Day01>(EXAMPLE_2)), 142);
+        assert_eq!(Day01::part2(&parse_example::<Day01>(EXAMPLE_3)), 281);
+    }
 }
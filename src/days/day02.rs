@@ -0,0 +1,136 @@
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+struct Draw {
+    blue: u32,
+    red: u32,
+    green: u32,
+}
+
+pub struct Game {
+    id: u32,
+    draws: Vec<Draw>,
+}
+
+fn parse_games(lines: &[String]) -> Result<Vec<Game>, ParseError> {
+    let mut games = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let (game, draws_str) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::end_of_line(index, line, "expected ':'"))?;
+        let game_id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::at(index, line, game, "expected 'Game <id>'"))?;
+        let game_id = parse_number::<u32>(index, line, game_id)?;
+
+        let mut draws = Vec::new();
+        for item in draws_str.split(';') {
+            let mut draw = Draw {
+                blue: 0,
+                red: 0,
+                green: 0,
+            };
+
+            for cubes in item.split(',') {
+                let cubes = cubes.trim();
+                let (num, color) = cubes
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(index, line, cubes, "expected '<number> <color>'"))?;
+                let num = parse_number::<u32>(index, line, num)?;
+                match color {
+                    "blue" => draw.blue += num,
+                    "red" => draw.red += num,
+                    "green" => draw.green += num,
+                    _ => return Err(ParseError::at(index, line, color, "unknown color")),
+                }
+            }
+
+            draws.push(draw);
+        }
+
+        games.push(Game { id: game_id, draws });
+    }
+
+    Ok(games)
+}
+
+fn sum_of_possible_ids(games: &[Game]) -> u32 {
+    let mut sum = 0;
+
+    for game in games {
+        // Check if draw is possible based on the limits
+        let failing_draws = game.draws.iter().filter(|&d| d.red > 12 || d.green > 13 || d.blue > 14).count();
+        if failing_draws == 0 {
+            sum += game.id;
+        }
+    }
+
+    sum
+}
+
+fn sum_of_powers(games: &[Game]) -> u32 {
+    let mut sum_of_powers = 0;
+
+    for game in games {
+        // Iterate over draws to create another draw comprised on the largest values for each color
+        let mut minimum_draw = Draw {
+            blue: 0,
+            red: 0,
+            green: 0,
+        };
+        game.draws.iter().for_each(|d| {
+            minimum_draw.blue = minimum_draw.blue.max(d.blue);
+            minimum_draw.red = minimum_draw.red.max(d.red);
+            minimum_draw.green = minimum_draw.green.max(d.green);
+        });
+
+        let power = minimum_draw.blue * minimum_draw.red * minimum_draw.green;
+        sum_of_powers += power;
+    }
+
+    sum_of_powers
+}
+
+pub const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_games(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        sum_of_possible_ids(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        sum_of_powers(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day02>(EXAMPLE);
+        assert_eq!(Day02::part1(&input), 8);
+        assert_eq!(Day02::part2(&input), 2286);
+    }
+}
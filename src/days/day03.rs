@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+
+fn compute_results(grid: &Grid<u8>) -> (u32, u32) {
+    let mut sum = 0;
+    // gear position -> adjacent numbers
+    let mut gears: HashMap<Point, Vec<u32>> = HashMap::new();
+
+    for y in 0..grid.height as i64 {
+        let mut x = 0;
+        while x < grid.width as i64 {
+            if !grid[Point::new(x, y)].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            // Scan the whole number
+            let start = x;
+            let mut number = 0;
+            while let Some(digit) = grid.get(Point::new(x, y)).filter(|c| c.is_ascii_digit()) {
+                number = number * 10 + (digit - b'0') as u32;
+                x += 1;
+            }
+
+            // Symbols are any character that is not a number or a dot, around any of the digits
+            let symbols = (start..x)
+                .flat_map(|x| grid.all_neighbors_where(Point::new(x, y), |&c| c != b'.' && !c.is_ascii_digit()))
+                .collect::<HashSet<_>>();
+
+            if !symbols.is_empty() {
+                sum += number;
+            }
+            for (point, &symbol) in symbols {
+                if symbol == b'*' {
+                    gears.entry(point).or_default().push(number);
+                }
+            }
+        }
+    }
+
+    // Sum of ration in gears that have more than 1 adjacent numbers
+    let gear_ratio = gears
+        .values()
+        .filter(|numbers| numbers.len() > 1)
+        .map(|numbers| numbers.iter().product::<u32>())
+        .sum::<u32>();
+
+    (sum, gear_ratio)
+}
+
+pub const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(position) = line.find(|c: char| !c.is_ascii_graphic()) {
+                return Err(ParseError::at(index, line, &line[position..], "invalid character"));
+            }
+        }
+        Grid::parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        compute_results(input).0
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        compute_results(input).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day03>(EXAMPLE);
+        assert_eq!(Day03::part1(&input), 4361);
+        assert_eq!(Day03::part2(&input), 467835);
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::{HashSet, HashMap};
+
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+
+pub struct Card {
+    lucky_numbers: HashSet<u32>,
+    my_numbers: HashSet<u32>,
+}
+
+fn parse_numbers(index: usize, line: &str, numbers: &str) -> Result<HashSet<u32>, ParseError> {
+    numbers.split_whitespace().map(|x| parse_number::<u32>(index, line, x)).collect()
+}
+
+fn parse_cards(input: &[String]) -> Result<Vec<Card>, ParseError> {
+    input.iter().enumerate().map(|(index, line)| {
+        let (_, numbers) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::end_of_line(index, line, "expected ':'"))?;
+        let (lucky_numbers, my_numbers) = numbers
+            .split_once('|')
+            .ok_or_else(|| ParseError::end_of_line(index, line, "expected '|'"))?;
+
+        Ok(Card {
+            lucky_numbers: parse_numbers(index, line, lucky_numbers)?,
+            my_numbers: parse_numbers(index, line, my_numbers)?,
+        })
+    }).collect()
+}
+
+fn calculate_result(input: &[Card]) -> (u32, u32) {
+    let mut sum = 0;
+    // Card number to number of cards with this number
+    let mut cards_map: HashMap<u32, u32> = HashMap::new();
+
+    // Rely on the fact that cards come sequentially. Alternative would be parse from the string
+    for (card_number, card) in (1..).zip(input) {
+        // First appearance of card or increase the number of cards
+        // Since by the time the original card is processed (from the strings) there is no way to increase the number of this card,
+        // we can hold on to the final number of this card
+        let number_of_current_cards_of_this_card = *cards_map
+            .entry(card_number)
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+
+        let numbers_in_both = card.lucky_numbers.intersection(&card.my_numbers).count();
+        let points = if numbers_in_both > 0 {
+            2u32.pow((numbers_in_both - 1) as u32)
+        } else {
+            0
+        };
+
+        sum += points; // Points for the first problem don't consider the cloned cards
+
+        // Add N cloned cards for the `numbers_in_both` subsequent cards, where N is the number of cards of the current card
+        for i in 1..=numbers_in_both {
+            cards_map
+            .entry(card_number+i as u32) // take the i-th next card
+            .and_modify(|x| *x += number_of_current_cards_of_this_card) // for each card of the current card, increase the number of cards of the i-th next card
+            .or_insert(number_of_current_cards_of_this_card);  // at least this amount of cards of the i-th next card
+        }
+    }
+
+    let total_number_of_cards = cards_map.values().sum::<u32>();
+
+    (sum, total_number_of_cards)
+}
+
+pub const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_cards(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        calculate_result(input).0
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        calculate_result(input).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day04>(EXAMPLE);
+        assert_eq!(Day04::part1(&input), 13);
+        assert_eq!(Day04::part2(&input), 30);
+    }
+}
@@ -0,0 +1,292 @@
+use crate::error::{get_line, parse_number, ParseError};
+use crate::solution::Solution;
+use crate::util::interval::{Interval, IntervalMap, IntervalSet};
+
+#[derive(Debug)]
+struct Map {
+    destination_start: i64,
+    source_start: i64,
+    length: i64,
+}
+
+// Parses the numbers of `fragments`, which are all non-negative in the almanac
+fn parse_values(index: usize, line: &str, fragments: &[&str]) -> Result<Vec<i64>, ParseError> {
+    fragments
+        .iter()
+        .map(|s| match parse_number::<i64>(index, line, s)? {
+            value if value < 0 => Err(ParseError::at(index, line, s, "expected a non-negative number")),
+            value => Ok(value),
+        })
+        .collect()
+}
+
+// Checks that the range of `length` values from each of `starts` is non-empty and its numbers fit in an i64
+fn check_range(index: usize, line: &str, starts: &[i64], length: i64, fragment: &str) -> Result<(), ParseError> {
+    if length <= 0 {
+        return Err(ParseError::at(index, line, fragment, "expected a positive length"));
+    }
+    if starts.iter().any(|start| start.checked_add(length).is_none()) {
+        return Err(ParseError::at(index, line, fragment, "range is too large"));
+    }
+    Ok(())
+}
+
+impl Map {
+    // Line contains the destination start, source start and length, in this order
+    fn parse(index: usize, line: &str) -> Result<Self, ParseError> {
+        let fragments = line.split_whitespace().collect::<Vec<_>>();
+        let values = parse_values(index, line, &fragments)?;
+        if values.len() != 3 {
+            return Err(ParseError::at(index, line, line, "expected 3 numbers"));
+        }
+        check_range(index, line, &values[..2], values[2], fragments[2])?;
+
+        Ok(Map {
+            destination_start: values[0],
+            source_start: values[1],
+            length: values[2],
+        })
+    }
+
+    // Adds the line to the mapping of its section
+    fn insert_into(&self, map: &mut IntervalMap) {
+        map.insert(Interval::with_length(self.source_start, self.length), self.destination_start - self.source_start);
+    }
+}
+
+// Conversion from one category to the next, such as seed to soil
+#[derive(Debug)]
+struct CategoryMap {
+    source: String,
+    destination: String,
+    map: IntervalMap,
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    // Each map's source is the previous map's destination, starting from seed
+    maps: Vec<CategoryMap>,
+}
+
+impl Almanac {
+    /// Categories in the order of the almanac, starting with seed
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        let last = self.maps.last().map_or("seed", |m| m.destination.as_str());
+        self.maps.iter().map(|m| m.source.as_str()).chain(std::iter::once(last))
+    }
+
+    /// Composed mapping from category `from` to category `to`, `None` if either is unknown or `to` comes before `from`
+    pub fn mapping(&self, from: &str, to: &str) -> Option<IntervalMap> {
+        let start = self.categories().position(|category| category == from)?;
+        let end = self.categories().position(|category| category == to)?;
+        let maps = self.maps.get(start..end)?;
+        Some(maps.iter().fold(IntervalMap::new(), |composed, m| composed.then(&m.map)))
+    }
+
+    /// Mapping from seed to the last category of the almanac, which is location in the puzzle
+    pub fn seed_to_location(&self) -> IntervalMap {
+        let last = self.categories().last().unwrap();
+        self.mapping("seed", last).unwrap()
+    }
+}
+
+// Parses a header such as "seed-to-soil map:" into its source and destination
+fn parse_header(index: usize, line: &str) -> Result<(String, String), ParseError> {
+    let (source, destination) = line
+        .strip_suffix(" map:")
+        .and_then(|name| name.split_once("-to-"))
+        .filter(|(source, destination)| !source.is_empty() && !destination.is_empty())
+        .ok_or_else(|| ParseError::at(index, line, line, "expected map header as 'X-to-Y map:'"))?;
+    Ok((source.to_owned(), destination.to_owned()))
+}
+
+fn parse_almanac(input: &[String]) -> Result<Almanac, ParseError> {
+    // Parse seeds in first line
+    let first_line = get_line(input, 0, "seeds")?;
+    let numbers = first_line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::at(0, first_line, first_line, "expected 'seeds:'"))?
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let seeds = parse_values(0, first_line, &numbers)?;
+    // Both parts need a seed, and part 2 reads them as pairs
+    match numbers.last() {
+        None => return Err(ParseError::at(0, first_line, first_line, "expected at least one seed")),
+        Some(last) if numbers.len() % 2 != 0 => {
+            return Err(ParseError::at(0, first_line, last, "expected seeds as pairs of start and length"));
+        }
+        _ => {}
+    }
+    for (pair, fragments) in seeds.chunks_exact(2).zip(numbers.chunks_exact(2)) {
+        check_range(0, first_line, &pair[..1], pair[1], fragments[1])?;
+    }
+
+    // Parse the rest of the lines, the maps must be preceded by their header
+    let mut maps: Vec<CategoryMap> = Vec::new();
+    for (index, line) in input.iter().enumerate().skip(1) {
+        if line.is_empty() {
+            continue;
+        } else if line.ends_with("map:") {
+            let (source, destination) = parse_header(index, line)?;
+            let previous = maps.last().map_or("seed", |m| m.destination.as_str());
+            if source != previous {
+                let message = format!("expected a map from '{}', the previous destination", previous);
+                return Err(ParseError::at(index, line, &line[..source.len()], message));
+            }
+            maps.push(CategoryMap { source, destination, map: IntervalMap::new() });
+            continue;
+        }
+
+        let values = Map::parse(index, line)?;
+        match maps.last_mut() {
+            Some(category_map) => values.insert_into(&mut category_map.map),
+            None => return Err(ParseError::at(index, line, line, "expected map header")),
+        }
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+// Problem 1 - Apply all maps to each seed
+fn lowest_location(almanac: &Almanac) -> u64 {
+    let seed_to_location = almanac.seed_to_location();
+    almanac.seeds.iter().map(|&seed| seed_to_location.get(seed)).min().unwrap() as u64
+}
+
+// Problem 2 - Seeds are given as pairs of start and length, map the whole ranges at once
+fn lowest_location_of_ranges(almanac: &Almanac) -> u64 {
+    let seeds = almanac.seeds
+        .chunks_exact(2)
+        .map(|pair| Interval::with_length(pair[0], pair[1]))
+        .collect::<IntervalSet>();
+
+    almanac.seed_to_location().map_set(&seeds).min().unwrap() as u64
+}
+
+pub const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_almanac(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        lowest_location(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        lowest_location_of_ranges(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day05>(EXAMPLE);
+        assert_eq!(Day05::part1(&input), 35);
+        assert_eq!(Day05::part2(&input), 46);
+    }
+
+    #[test]
+    fn test_seed_to_location() {
+        let seed_to_location = parse_example::<Day05>(EXAMPLE).seed_to_location();
+        assert_eq!([79, 14, 55, 13].map(|seed| seed_to_location.get(seed)), [82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn test_mapping() {
+        let almanac = parse_example::<Day05>(EXAMPLE);
+        assert_eq!(almanac.categories().count(), 8);
+        // Soil 81 has fertilizer 81, water 81, light 74, temperature 78 and humidity 78
+        assert_eq!(almanac.mapping("soil", "humidity").unwrap().get(81), 78);
+        assert_eq!(almanac.mapping("light", "light").unwrap().get(74), 74);
+        assert!(almanac.mapping("location", "seed").is_none());
+        assert!(almanac.mapping("seed", "weather").is_none());
+
+        // A shorter almanac, with other categories
+        let almanac = parse_example::<Day05>("seeds: 1 5\n\nseed-to-color map:\n10 0 3\n\ncolor-to-size map:\n0 11 1");
+        assert_eq!(almanac.seed_to_location().get(1), 0);
+        assert_eq!(Day05::part2(&almanac), 0);
+    }
+
+    #[test]
+    fn test_broken_chain() {
+        let lines = ["seeds: 1 2", "seed-to-soil map:", "1 2 3", "water-to-light map:"].map(String::from);
+        let error = Day05::parse(&lines).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (4, 1, "water"));
+        let lines = ["seeds: 1 2", "seed to soil map:"].map(String::from);
+        assert_eq!(Day05::parse(&lines).err().unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_invalid_seeds() {
+        let error = Day05::parse(&["seeds:".to_owned()]).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 1, "seeds:"));
+        let error = Day05::parse(&["seeds: 79 14 55".to_owned(), "seed-to-soil map:".to_owned()]).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 14, "55"));
+
+        let error_of = |seeds: &str| {
+            let lines = [seeds, "", "seed-to-soil map:", "50 98 2"].map(String::from);
+            Day05::parse(&lines).err().unwrap()
+        };
+        let error = error_of("seeds: 79 0");
+        assert_eq!((error.column, error.text.as_str(), error.message.as_str()), (11, "0", "expected a positive length"));
+        let error = error_of("seeds: 79 -14");
+        assert_eq!((error.column, error.message.as_str()), (11, "expected a non-negative number"));
+        let error = error_of("seeds: -79 14");
+        assert_eq!((error.column, error.message.as_str()), (8, "expected a non-negative number"));
+        assert_eq!(error_of("seeds: 9223372036854775807 1").message, "range is too large");
+
+        // The same checks apply to the maps
+        let lines = ["seeds: 79 14", "", "seed-to-soil map:", "50 98 -2"].map(String::from);
+        let error = Day05::parse(&lines).err().unwrap();
+        assert_eq!((error.line, error.column, error.message.as_str()), (4, 7, "expected a non-negative number"));
+        let lines = ["seeds: 79 14", "", "seed-to-soil map:", "50 98 0"].map(String::from);
+        assert_eq!(Day05::parse(&lines).err().unwrap().message, "expected a positive length");
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error::{get_line, parse_number, ParseError};
+use crate::solution::Solution;
+
+pub struct Race {
+    time: u64,
+    max_distance: u64,
+}
+
+// Gets the values following the label in the given line, such as "Time:"
+fn values<'a>(input: &'a [String], index: usize, label: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let line = get_line(input, index, label)?;
+    let values = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(index, line, line, format!("expected '{}'", label)))?;
+    Ok((line, values))
+}
+
+fn parse(input: &[String]) -> Result<Vec<Race>, ParseError> {
+    // Parse first line - Times
+    let (line, times) = values(input, 0, "Time:")?;
+    let times = times
+        .split_whitespace()
+        .map(|s| parse_number::<u64>(0, line, s))
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    // Parse max distances
+    let (line, max_distances) = values(input, 1, "Distance:")?;
+    let max_distances = max_distances
+        .split_whitespace()
+        .map(|s| parse_number::<u64>(1, line, s))
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    if times.len() != max_distances.len() {
+        return Err(ParseError::end_of_line(1, line, format!("expected {} distances", times.len())));
+    }
+
+    // Take both and transform into Race
+    Ok(times
+        .iter()
+        .zip(max_distances.iter())
+        .map(|pair| Race {
+            time: *pair.0,
+            max_distance: *pair.1,
+        })
+        .collect())
+}
+
+fn parse_part2(input: &[String]) -> Result<Vec<Race>, ParseError> {
+    let (line, time) = values(input, 0, "Time:")?;
+    let time = time.replace(' ', "").parse::<u64>()
+    .map_err(|_| ParseError::at(0, line, time, "invalid number"))?;
+
+    let (line, max_distance) = values(input, 1, "Distance:")?;
+    let max_distance = max_distance.replace(' ', "").parse::<u64>()
+    .map_err(|_| ParseError::at(1, line, max_distance, "invalid number"))?;
+
+    Ok(vec![
+        Race {
+            time,
+            max_distance,
+        }
+    ])
+}
+
+fn calculate_result(races: &Vec<Race>) -> u64 {
+    let mut product = 1;
+
+    for race in races {
+        let t = race.time as f64;
+        let d = race.max_distance as f64;
+
+        // t = (T +- sqrt(T² - 4D))/2
+        let t1 = (t + (t * t - 4.0 * d).sqrt()) / 2.0;
+        let t2 = (t - (t * t - 4.0 * d).sqrt()) / 2.0;
+
+        let t1 = if t1.fract().abs() > 0.0 {
+            t1.floor() as u64
+        } else {
+            t1.floor() as u64 - 1
+        };
+
+        let t2 = if t2.fract().abs() > 0.0 {
+            t2.ceil() as u64
+        } else {
+            t2.ceil() as u64 + 1
+        };
+
+        let number_of_ways = t1 - t2 + 1;
+        product *= number_of_ways;
+    }
+
+    product
+}
+
+pub const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    // Part 1 reads several races, part 2 reads a single race ignoring the spaces
+    type Input = (Vec<Race>, Vec<Race>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok((parse(lines)?, parse_part2(lines)?))
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        calculate_result(&input.0)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        calculate_result(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day06>(EXAMPLE);
+        assert_eq!(Day06::part1(&input), 288);
+        assert_eq!(Day06::part2(&input), 71503);
+    }
+}
@@ -1,13 +1,15 @@
 use std::collections::BTreeSet;
 
-use advent_of_code_2023::read_lines_as_vec;
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
 use itertools::Itertools;
 use strum::IntoEnumIterator;
 
+// Jokers only exist in part 2, where J cards are the weakest card and act as wildcards
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
 #[derive(strum_macros::EnumIter)]
 enum Cards {
-    J = 0,
+    Joker = 0,
     Two,
     Three,
     Four,
@@ -17,13 +19,14 @@ enum Cards {
     Eight,
     Nine,
     T,
+    J,
     Q,
     K,
     A,
 }
 
 #[derive(Debug, Copy, Clone)]
-struct Hand{
+pub struct Hand{
     // Five cards
     cards: [Cards; 5],
 }
@@ -87,20 +90,20 @@ impl PartialEq for Game {
 
 // Given a Hand, identify what is its type
 fn identify_hand(hand: &Hand) -> HandTypes {
-    let mut counts = [0u32; 13];
+    let mut counts = [0u32; 14];
 
-    // Count number of J
-    let js = hand.cards.iter().filter(|&&card| card == Cards::J).count();
-    if js > 0 {
+    // Count number of jokers
+    let jokers = hand.cards.iter().filter(|&&card| card == Cards::Joker).count();
+    if jokers > 0 {
         let mut highest_hand_type = HandTypes::HighCard;
-        // Replace J with each of the other cards and call the function again
+        // Replace the jokers with each of the other cards and call the function again
         for card in Cards::iter() {
-            if card == Cards::J {
+            if card == Cards::Joker || card == Cards::J {
                 continue;
             }
 
             let mut new_hand = *hand;
-            new_hand.cards.iter_mut().for_each(|c| if *c == Cards::J { *c = card; });
+            new_hand.cards.iter_mut().for_each(|c| if *c == Cards::Joker { *c = card; });
             let hand_type = identify_hand(&new_hand);
             if hand_type > highest_hand_type {
                 highest_hand_type = hand_type;
@@ -133,35 +136,50 @@ fn identify_hand(hand: &Hand) -> HandTypes {
     }
 }
 
-fn calculate_result(input: &Vec<String>) -> u32 {
-    let mut games = BTreeSet::new();
+fn parse_hands(input: &[String]) -> Result<Vec<(Hand, u32)>, ParseError> {
+    let mut hands = Vec::new();
 
-    for line in input {
-        let (hand, bid) = line.split_whitespace().take(2).collect_tuple().unwrap();
-        let bid = bid.parse::<u32>().unwrap();
+    for (index, line) in input.iter().enumerate() {
+        let (hand, bid) = line
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::at(index, line, line, "expected '<hand> <bid>'"))?;
+        let bid = parse_number::<u32>(index, line, bid)?;
 
         // Iterate over the 5 characters of the hand and convert them to Cards
-        let hand = hand.chars().map(|c| match c {
-            'J' => Cards::J,
-            '2' => Cards::Two,
-            '3' => Cards::Three,
-            '4' => Cards::Four,
-            '5' => Cards::Five,
-            '6' => Cards::Six,
-            '7' => Cards::Seven,
-            '8' => Cards::Eight,
-            '9' => Cards::Nine,
-            'T' => Cards::T,
-            'Q' => Cards::Q,
-            'K' => Cards::K,
-            'A' => Cards::A,
-            _ => panic!("Invalid card"),
-        }).collect::<Vec<_>>();
+        let cards = hand.char_indices().map(|(i, c)| match c {
+            '2' => Ok(Cards::Two),
+            '3' => Ok(Cards::Three),
+            '4' => Ok(Cards::Four),
+            '5' => Ok(Cards::Five),
+            '6' => Ok(Cards::Six),
+            '7' => Ok(Cards::Seven),
+            '8' => Ok(Cards::Eight),
+            '9' => Ok(Cards::Nine),
+            'T' => Ok(Cards::T),
+            'J' => Ok(Cards::J),
+            'Q' => Ok(Cards::Q),
+            'K' => Ok(Cards::K),
+            'A' => Ok(Cards::A),
+            _ => Err(ParseError::at(index, line, &hand[i..i + c.len_utf8()], "invalid card")),
+        }).collect::<Result<Vec<_>, _>>()?;
 
         let hand = Hand {
-            cards: [hand[0], hand[1], hand[2], hand[3], hand[4]],
+            cards: cards
+                .try_into()
+                .map_err(|_| ParseError::at(index, line, hand, "expected 5 cards"))?,
         };
 
+        hands.push((hand, bid));
+    }
+
+    Ok(hands)
+}
+
+fn calculate_winnings<T: Iterator<Item = (Hand, u32)>>(hands: T) -> u32 {
+    let mut games = BTreeSet::new();
+
+    for (hand, bid) in hands {
         let hand_type = identify_hand(&hand);
         let game = Game { hand: (hand, hand_type), bid };
         games.insert(game);
@@ -176,16 +194,49 @@ fn calculate_result(input: &Vec<String>) -> u32 {
     winnings
 }
 
-fn main() {
-    let _input = read_lines_as_vec("inputs/7.txt").unwrap();
-
-    let _example = vec![
-        "32T3K 765".to_string(),
-        "T55J5 684".to_string(),
-        "KK677 28".to_string(),
-        "KTJJT 220".to_string(),
-        "QQQJA 483".to_string(),
-    ];
-    let winnings = calculate_result(&_input);
-    println!("Winnings: {}", winnings);
+pub const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(Hand, u32)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_hands(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        calculate_winnings(input.iter().cloned())
+    }
+
+    // Part 2 - J cards are jokers
+    fn part2(input: &Self::Input) -> u32 {
+        calculate_winnings(input.iter().map(|(hand, bid)| {
+            let mut hand = *hand;
+            hand.cards.iter_mut().for_each(|c| if *c == Cards::J { *c = Cards::Joker; });
+            (hand, *bid)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day07>(EXAMPLE);
+        assert_eq!(Day07::part1(&input), 6440);
+        assert_eq!(Day07::part2(&input), 5905);
+    }
 }
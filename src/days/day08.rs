@@ -0,0 +1,233 @@
+use crate::error::{get_line, ParseError};
+use crate::solution::Solution;
+use crate::util::cycle::find_cycle;
+use crate::util::math::first_common;
+use regex::Regex;
+use std::collections::HashMap;
+
+type NodeMap = HashMap<String, (String, String)>;
+
+// Part 1
+// Iterate over sequence until we find node ZZZ, count the number of steps
+fn calculate_part_1(sequence: &str, nodes: &NodeMap, starting_node: &str) -> u32 {
+    let mut steps = 0;
+    let mut current_node = starting_node;
+
+    if !nodes.contains_key(current_node) {
+        return 0;
+    }
+
+    for char in sequence.chars().cycle() {
+        steps += 1;
+        current_node = if char == 'L' {
+            &nodes.get(current_node).unwrap().0
+        } else {
+            &nodes.get(current_node).unwrap().1
+        };
+
+        if current_node.ends_with('Z') {
+            break;
+        }
+    }
+
+    steps
+}
+
+// Steps after which the ghost leaving `start` is on a node ending with Z, as (first, period) pairs. Only the
+// nodes met in the ghost's cycle are met again, the ones before it are ignored.
+fn ghost_cycles(sequence: &str, nodes: &NodeMap, start: &str) -> Vec<(i64, i64)> {
+    // The state of a ghost is its node and the index of its next instruction
+    let instructions = sequence.as_bytes();
+    let step = |&(node, i): &(&str, usize)| {
+        let (left, right) = &nodes[node];
+        let next = if instructions[i] == b'L' { left } else { right };
+        (next.as_str(), (i + 1) % instructions.len())
+    };
+
+    let cycle = find_cycle(&(start, 0), step);
+    let mut state = (start, 0);
+    let mut cycles = Vec::new();
+    for steps in 0..cycle.start + cycle.length {
+        if steps >= cycle.start && state.0.ends_with('Z') {
+            cycles.push((steps as i64, cycle.length as i64));
+        }
+        state = step(&state);
+    }
+    cycles
+}
+
+// Part 2 - Start simultaneously from all nodes that end with A, until they all simultaneously end in nodes ending with Z
+// Count the number of steps
+fn calculate_part_2(sequence: &str, nodes: &NodeMap) -> u64 {
+    let ghosts = nodes
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|start| ghost_cycles(sequence, nodes, start))
+        .collect::<Vec<_>>();
+    if ghosts.is_empty() {
+        return 0;
+    }
+
+    // Each ghost may meet several nodes ending with Z in its cycle, usually just one, so try each combination
+    let mut combinations: Vec<Vec<(i64, i64)>> = vec![Vec::new()];
+    for cycles in &ghosts {
+        combinations = combinations
+            .iter()
+            .flat_map(|combination| cycles.iter().map(move |&cycle| [&combination[..], &[cycle]].concat()))
+            .collect();
+    }
+
+    combinations
+        .iter()
+        .filter_map(|combination| first_common(combination))
+        .min()
+        .expect("the ghosts are never all on nodes ending with Z together") as u64
+}
+
+pub struct Network {
+    sequence: String,
+    nodes: NodeMap,
+}
+
+fn parse_network(input: &[String]) -> Result<Network, ParseError> {
+    let sequence = get_line(input, 0, "sequence of instructions")?;
+    if sequence.is_empty() {
+        return Err(ParseError::at(0, sequence, sequence, "expected at least one instruction"));
+    }
+    if let Some((i, c)) = sequence.char_indices().find(|&(_, c)| c != 'L' && c != 'R') {
+        return Err(ParseError::at(0, sequence, &sequence[i..i + c.len_utf8()], "invalid instruction"));
+    }
+    if let Some(line) = input.get(1).filter(|line| !line.is_empty()) {
+        return Err(ParseError::at(1, line, line, "expected empty line"));
+    }
+
+    let regex = Regex::new(r"^((?:\d|\w)+) = \(((?:\d|\w)+), ((?:\d|\w)+)\)$").unwrap();
+    let mut nodes: NodeMap = HashMap::new();
+    for (index, line) in input.iter().enumerate().skip(2) {
+        let captures = regex
+            .captures(line)
+            .ok_or_else(|| ParseError::at(index, line, line, "expected '<node> = (<left>, <right>)'"))?;
+        let label = captures.get(1).unwrap().as_str();
+        let left = captures.get(2).unwrap().as_str();
+        let right = captures.get(3).unwrap().as_str();
+
+        nodes.insert(label.to_owned(), (left.to_owned(), right.to_owned()));
+    }
+
+    // All nodes need to lead somewhere
+    for (index, line) in input.iter().enumerate().skip(2) {
+        let captures = regex.captures(line).unwrap();
+        for destination in [captures.get(2).unwrap(), captures.get(3).unwrap()] {
+            if !nodes.contains_key(destination.as_str()) {
+                return Err(ParseError::at(index, line, &line[destination.range()], "unknown node"));
+            }
+        }
+    }
+
+    Ok(Network { sequence: sequence.to_owned(), nodes })
+}
+
+pub const EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+pub const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+// Example for part 2
+pub const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[
+        ("example", EXAMPLE),
+        ("example_2", EXAMPLE_2),
+        ("example_3", EXAMPLE_3),
+    ];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_network(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        calculate_part_1(&input.sequence, &input.nodes, "AAA")
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        calculate_part_2(&input.sequence, &input.nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_invalid_instruction() {
+        let lines = ["LRé", "", "AAA = (AAA, AAA)"].map(String::from);
+        let error = Day08::parse(&lines).err().unwrap();
+        assert_eq!((error.column, error.text.as_str()), (3, "é"));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day08::part1(&parse_example::<Day08>(EXAMPLE)), 2);
+        assert_eq!(Day08::part1(&parse_example::<Day08>(EXAMPLE_2)), 6);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day08::part2(&parse_example::<Day08>(EXAMPLE_3)), 6);
+    }
+
+    #[test]
+    fn test_offset_cycles() {
+        let network = parse_example::<Day08>(EXAMPLE_3);
+        assert_eq!(ghost_cycles(&network.sequence, &network.nodes, "11A"), [(2, 2)]);
+        assert_eq!(ghost_cycles(&network.sequence, &network.nodes, "22A"), [(3, 6), (6, 6)]);
+
+        // The first ghost reaches 1Z after 3 steps then every 2 steps, the second after 2 steps then every 3 steps
+        let network = parse_example::<Day08>(
+            "\
+L
+
+1A = (1B, 1B)
+1B = (1C, 1C)
+1C = (1Z, 1Z)
+1Z = (1C, 1C)
+2A = (2B, 2B)
+2B = (2Z, 2Z)
+2Z = (2C, 2C)
+2C = (2D, 2D)
+2D = (2Z, 2Z)",
+        );
+        assert_eq!(Day08::part2(&network), 5);
+    }
+}
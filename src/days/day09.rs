@@ -0,0 +1,73 @@
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+use itertools::Itertools;
+
+// Part 1 and 2 - Process the sequence to obtain the next number
+fn process_sequence(sequence: &[i64], backwards: bool) -> i64 {
+    // Stop condition is all elements are zero
+    if sequence.iter().all(|&n| n == 0) {
+        return 0;
+    }
+
+    // Calculate difference between each number
+    let differences = sequence.iter().tuple_windows().map(|(a, b)| b-a).collect::<Vec<_>>();
+
+    // Are we calculating the next number or the number before the first
+    if backwards {
+        sequence.first().unwrap() - process_sequence(&differences, true)
+    } else {
+        sequence.last().unwrap() + process_sequence(&differences, false)
+    }
+}
+
+fn parse_sequences(lines: &[String]) -> Result<Vec<Vec<i64>>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            line.split_whitespace()
+                .map(|n| parse_number::<i64>(index, line, n))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+pub const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_sequences(lines)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        input.iter().map(|sequence| process_sequence(sequence, false)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        input.iter().map(|sequence| process_sequence(sequence, true)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day09>(EXAMPLE);
+        assert_eq!(Day09::part1(&input), 114);
+        assert_eq!(Day09::part2(&input), 2);
+    }
+}
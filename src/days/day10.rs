@@ -0,0 +1,249 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::bit_grid::BitGrid;
+use crate::util::grid::Grid;
+use crate::util::point::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pipes {
+    G,  // Ground / No pipe
+    V,  // Vertical
+    H,  // Horizontal
+    F,  // South-East
+    L,  // North-East
+    J,  // North-West
+    SW, // South-West (7)
+    S,  // Animal / Unknown pipe
+}
+
+impl TryFrom<u8> for Pipes {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'.' => Ok(Pipes::G),
+            b'|' => Ok(Pipes::V),
+            b'-' => Ok(Pipes::H),
+            b'F' => Ok(Pipes::F),
+            b'L' => Ok(Pipes::L),
+            b'J' => Ok(Pipes::J),
+            b'7' => Ok(Pipes::SW),
+            b'S' => Ok(Pipes::S),
+            _ => Err("unknown character"),
+        }
+    }
+}
+
+impl Pipes {
+    // Directions the pipe leads to, none for the ground and the animal
+    fn connections(self) -> &'static [Direction] {
+        match self {
+            Pipes::V => &[Direction::Up, Direction::Down],
+            Pipes::H => &[Direction::Left, Direction::Right],
+            Pipes::F => &[Direction::Right, Direction::Down],
+            Pipes::L => &[Direction::Up, Direction::Right],
+            Pipes::J => &[Direction::Up, Direction::Left],
+            Pipes::SW => &[Direction::Left, Direction::Down],
+            Pipes::G | Pipes::S => &[],
+        }
+    }
+}
+
+// Given a position and a direction, check if the neighbour in that direction connects back to this position
+fn neighbour_connects(pipes: &Grid<Pipes>, position: Point, direction: Direction) -> bool {
+    pipes
+        .get(position + direction)
+        .is_some_and(|&pipe| pipe == Pipes::S || pipe.connections().contains(&direction.reverse()))
+}
+
+// The type of pipe hidden under S, found from the neighbours that connect to it, if at least two do
+fn figure_s_type(pipes: &Grid<Pipes>, s_position: Point) -> Option<Pipes> {
+    let connected = Direction::ALL
+        .into_iter()
+        .filter(|&d| neighbour_connects(pipes, s_position, d))
+        .collect::<Vec<_>>();
+
+    // S only has 2 connecting neighbours in the puzzle, with more of them the loop is traced through the first pipe
+    [Pipes::V, Pipes::H, Pipes::F, Pipes::L, Pipes::J, Pipes::SW]
+        .into_iter()
+        .find(|pipe| pipe.connections().iter().all(|d| connected.contains(d)))
+}
+
+// Follows the pipes from S until we're back at S, leaving each pipe by its other end. Returns the positions that
+// belong to the loop along with the type of pipe hidden under S, or the position where the loop breaks.
+fn trace_loop(map: &Grid<Pipes>, s_position: Point) -> Result<(BitGrid, Pipes), Point> {
+    let s_tile_type = figure_s_type(map, s_position).ok_or(s_position)?;
+
+    // Store the positions that belong to the loop
+    let mut loop_positions = BitGrid::new(map.width, map.height);
+    loop_positions.insert(s_position);
+
+    let mut direction = s_tile_type.connections()[0];
+    let mut position = s_position + direction;
+    while map[position] != Pipes::S {
+        loop_positions.insert(position);
+        // The pipe must connect back to the previous one, and lead to another tile of the map
+        let connections = map[position].connections();
+        if !connections.contains(&direction.reverse()) {
+            return Err(position);
+        }
+        direction = *connections.iter().find(|&&d| d != direction.reverse()).unwrap();
+        if map.get(position + direction).is_none() {
+            return Err(position);
+        }
+        position += direction;
+    }
+
+    // S must connect back to the last pipe too
+    if !s_tile_type.connections().contains(&direction.reverse()) {
+        return Err(s_position);
+    }
+    Ok((loop_positions, s_tile_type))
+}
+
+pub struct PipeMap {
+    map: Grid<Pipes>,
+    loop_positions: BitGrid,
+    s_tile_type: Pipes,
+}
+
+fn parse_map(input: &[String]) -> Result<PipeMap, ParseError> {
+    let map = Grid::try_parse(input)?;
+    let last = input.len() - 1;
+    let s_position = map
+        .find(&Pipes::S)
+        .ok_or_else(|| ParseError::end_of_line(last, &input[last], "no starting position 'S' found"))?;
+
+    let (loop_positions, s_tile_type) = trace_loop(&map, s_position).map_err(|position| {
+        let (x, y) = (position.x as usize, position.y as usize);
+        ParseError::at(y, &input[y], &input[y][x..x + 1], "the loop through S breaks at this tile")
+    })?;
+    Ok(PipeMap { map, loop_positions, s_tile_type })
+}
+
+// Each tile becomes 3x3 cells with the pipe drawn through the middle ones, which opens the gaps between pipes
+// that squeeze past each other. A tile is inside the loop when its center can't be reached from the border.
+fn count_tiles_inside(map: &Grid<Pipes>, loop_positions: &BitGrid, s_tile_type: Pipes) -> u64 {
+    let center = |position: Point| position * 3 + Point::new(1, 1);
+
+    let mut walls = Grid::new(3 * map.width, 3 * map.height, false);
+    for position in loop_positions.iter() {
+        let tile_type = match map[position] {
+            Pipes::S => s_tile_type,
+            pipe => pipe,
+        };
+
+        let center = center(position);
+        walls[center] = true;
+        for &direction in tile_type.connections() {
+            walls[center + direction] = true;
+        }
+    }
+
+    let enclosed = walls.enclosed(|&wall| !wall);
+    (0..map.height as i64)
+        .flat_map(|y| (0..map.width as i64).map(move |x| Point::new(x, y)))
+        .filter(|&position| enclosed.get(center(position)))
+        .count() as u64
+}
+
+pub const EXAMPLE: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+pub const EXAMPLE_2: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeMap;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE), ("example_2", EXAMPLE_2)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_map(lines)
+    }
+
+    // Farthest point in the loop is half its length away
+    fn part1(input: &Self::Input) -> usize {
+        input.loop_positions.count_ones() / 2
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        count_tiles_inside(&input.map, &input.loop_positions, input.s_tile_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+    use crate::util::render::Style;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day10::part1(&parse_example::<Day10>(EXAMPLE)), 8);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day10::part2(&parse_example::<Day10>(EXAMPLE_2)), 4);
+    }
+
+    // The pipes squeeze past each other, which leaves a gap to the outside between them
+    #[test]
+    fn test_squeezing_pipes() {
+        let example = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        assert_eq!(Day10::part2(&parse_example::<Day10>(example)), 4);
+    }
+
+    // Only the pipes of the loop are kept, as drawn in the puzzle
+    #[test]
+    fn test_loop() {
+        let loop_positions = parse_example::<Day10>(EXAMPLE).loop_positions;
+        let grid = Grid::parse_str(EXAMPLE).unwrap();
+        let render = grid.render(|&c| c as char).style_cells(|point, _| {
+            if loop_positions.get(point) {
+                Style::new()
+            } else {
+                Style::new().glyph('.')
+            }
+        });
+        assert_eq!(render.to_string(), "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...\n");
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        let error = Day10::parse(&crate::input::split_lines("S-7\n|.|\nL-.")).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 3, "."));
+        // S with a single connecting neighbour
+        let error = Day10::parse(&crate::input::split_lines(".S-\n...")).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 2, "S"));
+        // The loop leaves the map
+        let error = Day10::parse(&crate::input::split_lines("S-\n|.")).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 2, "-"));
+    }
+}
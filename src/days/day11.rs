@@ -0,0 +1,140 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+use crate::util::sparse_grid::SparseGrid;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Element {
+    Empty,
+    Galaxy
+}
+
+impl TryFrom<u8> for Element {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'.' => Ok(Element::Empty),
+            b'#' => Ok(Element::Galaxy),
+            _ => Err("unknown element"),
+        }
+    }
+}
+
+pub struct Universe {
+    // Each galaxy holds the number of empty columns to its left and empty rows above it, which expand
+    galaxies: SparseGrid<Point>,
+}
+
+// Number of empty lines before each line
+fn empty_before(empty: impl Iterator<Item = bool>) -> Vec<i64> {
+    empty
+        .scan(0, |count, empty| {
+            let before = *count;
+            *count += empty as i64;
+            Some(before)
+        })
+        .collect()
+}
+
+fn parse_universe(lines: &[String]) -> Result<Universe, ParseError> {
+    let image = Grid::<Element>::try_parse(lines)?;
+
+    // Rows and columns that consist only of empty space need to be expanded
+    let empty_rows_above = empty_before(image.rows().map(|row| row.iter().all(|&e| e == Element::Empty)));
+    let empty_cols_left = empty_before(image.columns().map(|mut column| column.all(|&e| e == Element::Empty)));
+
+    let galaxies = (0..image.height as i64)
+        .flat_map(|y| (0..image.width as i64).map(move |x| Point::new(x, y)))
+        .filter(|&point| image[point] == Element::Galaxy)
+        .map(|point| (point, Point::new(empty_cols_left[point.x as usize], empty_rows_above[point.y as usize])))
+        .collect();
+
+    Ok(Universe { galaxies })
+}
+
+// Each empty row or column is replaced by `expansion` empty rows or columns
+fn sum_of_shortest_distances(universe: &Universe, expansion: u64) -> u64 {
+    let galaxies = universe.galaxies.iter().collect::<Vec<_>>();
+
+    // For every pair of galaxies
+    let mut sum_of_shortest_distances = 0;
+    for (i, (first, first_empty)) in galaxies.iter().enumerate() {  // moves sequentially from galaxy x to n
+        for (second, second_empty) in galaxies.iter().skip(i + 1) {  // from galaxy x+1 onwards, guarantees unique pairs
+            // Initial distance between them
+            sum_of_shortest_distances += first.manhattan_distance(second) as u64;
+
+            // The empty rows and columns between the two galaxies, each increasing the distance
+            let empty_between = first_empty.manhattan_distance(second_empty) as u64;
+            sum_of_shortest_distances += empty_between * (expansion - 1);
+        }
+    }
+
+    sum_of_shortest_distances
+}
+
+pub const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Universe;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_universe(lines)
+    }
+
+    // Empty space doubles
+    fn part1(input: &Self::Input) -> u64 {
+        sum_of_shortest_distances(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        sum_of_shortest_distances(input, 1_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day11>(EXAMPLE);
+        assert_eq!(Day11::part1(&input), 374);
+        assert_eq!(Day11::part2(&input), 82000210);
+    }
+
+    #[test]
+    fn test_expansion() {
+        let input = parse_example::<Day11>(EXAMPLE);
+        assert_eq!(sum_of_shortest_distances(&input, 10), 1030);
+        assert_eq!(sum_of_shortest_distances(&input, 100), 8410);
+    }
+
+    #[test]
+    fn test_empty_before() {
+        // Rows 3 and 7 and columns 2, 5 and 8 are empty
+        let galaxies = parse_example::<Day11>(EXAMPLE).galaxies;
+        assert_eq!(galaxies.len(), 9);
+        assert_eq!(galaxies[Point::new(3, 0)], Point::new(1, 0));
+        assert_eq!(galaxies[Point::new(4, 9)], Point::new(1, 2));
+        assert_eq!(galaxies[Point::new(9, 6)], Point::new(3, 1));
+    }
+}
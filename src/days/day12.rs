@@ -1,4 +1,5 @@
-use advent_of_code_2023::read_lines_as_vec;
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
 use std::collections::HashMap;
 use itertools::{Itertools};
 
@@ -49,37 +50,72 @@ fn solve(springs: &[char], counts: &[u32], chunk: u32, cache: &mut HashMap<(usiz
     arrangements
 }
 
-fn calculate_result(input: &[String]) -> (u64, u64) {
-    input.iter().map(|line| {
-        let mut cache = HashMap::new();
+pub struct Record {
+    springs: String,
+    counts: Vec<u32>,
+}
+
+fn parse_records(input: &[String]) -> Result<Vec<Record>, ParseError> {
+    input.iter().enumerate().map(|(index, line)| {
+        let (springs, counts) = line.split_whitespace().collect_tuple()
+            .ok_or_else(|| ParseError::at(index, line, line, "expected springs and counts"))?;
+        if let Some((i, c)) = springs.char_indices().find(|(_, c)| !matches!(c, '.' | '#' | '?')) {
+            return Err(ParseError::at(index, line, &springs[i..i + c.len_utf8()], "unknown spring"));
+        }
+        let counts = counts.split(',').map(|s| parse_number::<u32>(index, line, s)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Record { springs: springs.to_owned(), counts })
+    }).collect()
+}
+
+fn count_arrangements(springs: &str, counts: &[u32]) -> u64 {
+    let mut cache = HashMap::new();
+    solve(&springs.chars().collect::<Vec<_>>(), counts, 0, &mut cache)
+}
+
+pub const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+pub struct Day12;
 
-        let (springs, counts) = line.split_whitespace().collect_tuple().unwrap();
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-        // Part 1
-        let counts = counts.split(',').map(|s| s.parse::<u32>().unwrap()).collect_vec();
-        let p1 = solve(&springs.chars().collect::<Vec<_>>(), &counts, 0, &mut cache);
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
 
-        // Part 2 - Unfold springs and counts 5 times
-        let counts = counts.repeat(5);
-        let springs = std::iter::once(springs).cycle().take(5).join("?");
-        let p2 = solve(&springs.chars().collect::<Vec<_>>(), &counts, 0, &mut cache);
-        (p1, p2)
-    }).reduce(|(p1, p2), elem| (p1 + elem.0, p2 + elem.1)).unwrap()
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_records(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        input.iter().map(|record| count_arrangements(&record.springs, &record.counts)).sum()
+    }
+
+    // Part 2 - Unfold springs and counts 5 times
+    fn part2(input: &Self::Input) -> u64 {
+        input.iter().map(|record| {
+            let counts = record.counts.repeat(5);
+            let springs = std::iter::once(&record.springs).cycle().take(5).join("?");
+            count_arrangements(&springs, &counts)
+        }).sum()
+    }
 }
 
-fn main() {
-    let _lines = read_lines_as_vec("inputs/12.txt").unwrap();
-
-    let _example = [
-        "???.### 1,1,3",
-        ".??..??...?##. 1,1,3",
-        "?#?#?#?#?#?#?#? 1,3,1,6",
-        "????.#...#... 4,1,1",
-        "????.######..#####. 1,6,5",
-        "?###???????? 3,2,1",
-    ].iter().map(|s| s.to_string()).collect::<Vec<_>>();
-
-    let (p1, p2) = calculate_result(&_lines);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day12>(EXAMPLE);
+        assert_eq!(Day12::part1(&input), 21);
+        assert_eq!(Day12::part2(&input), 525152);
+    }
 }
@@ -0,0 +1,184 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::grid::{Grid, GridView};
+use crate::util::point::Point;
+use itertools::Itertools;
+
+// Find the number of rows above the reflection line
+fn solve_map<R: PartialEq>(map: &[R]) -> Vec<u32> {
+    // Iterate over pairs of lines until we find identical ones
+    map.iter()
+        .tuple_windows()
+        .enumerate()
+        .filter_map(|(row_idx, (row_1, row_2))| {
+            if row_1 != row_2 {
+                return None;
+            }
+
+            // Interleave the forward iterator with the backward iterator to compare the corresponding lines
+            // with respect to the reflection line
+            if map
+                .iter()
+                .skip(row_idx + 1)
+                .interleave_shortest(map.iter().rev().skip(map.len() - row_idx - 1))
+                .chunks(2)
+                .into_iter()
+                .all(|chunk| {
+                    let mut chunk = chunk.into_iter();
+                    let row1 = chunk.next().unwrap();
+                    let row2 = chunk.next().unwrap_or(row1);  // if there is not 2nd row then the reflection is over
+                    row1 == row2
+                })
+            {
+                Some(1 + row_idx as u32)
+            } else {
+                None
+            }
+        }).collect::<Vec<_>>()
+}
+
+// Columns are solved as the rows of the transposed pattern
+fn solve_rows(pattern: GridView<'_, u8>) -> Vec<u32> {
+    solve_map(&pattern.rows().map(|row| row.collect::<Vec<_>>()).collect::<Vec<_>>())
+}
+
+#[derive(Debug, PartialEq)]
+enum Reflection {
+    None,
+    Row(u32),
+    Column(u32),
+}
+
+impl Reflection {
+    // Reflections accross a horizontal line are multiplied by 100, accross a vertical line are not multiplied
+    fn summary(&self) -> u32 {
+        match self {
+            Reflection::None => 0,
+            Reflection::Row(rows_above) => rows_above * 100,
+            Reflection::Column(columns_to_the_left) => *columns_to_the_left,
+        }
+    }
+}
+
+// Problem 1 - Find the only reflection line in the pattern
+fn find_reflection(pattern: &Grid<u8>) -> Reflection {
+    let solution = solve_rows(pattern.view());
+    assert!(solution.len() <= 1);  // First part of the problem assumes that there is only one reflection line
+
+    if solution.len() == 1 {
+        Reflection::Row(solution[0])
+    } else {
+        // Transpose and solve column-wise
+        let solution = solve_rows(pattern.view().transpose());
+        assert!(solution.len() <= 1);
+        if solution.len() == 1 {
+            Reflection::Column(solution[0])
+        } else {
+            Reflection::None
+        }
+    }
+}
+
+// Problem 2 - Fix the smudge within the reflection, which yields a new reflection line
+fn find_smudged_reflection(pattern: &Grid<u8>) -> Reflection {
+    let first_solution = find_reflection(pattern);
+
+    for row_idx in 0..pattern.height {
+        for column_idx in 0..pattern.width {
+            // Clone the pattern, flip character at (row_idx, column_idx) and solve
+            let mut pattern = pattern.clone();
+            let c = &mut pattern[Point::new(column_idx as i64, row_idx as i64)];
+            *c = if *c == b'#' { b'.' } else { b'#' };
+
+            // We need to check solutions for both rows and columns since there may be multiple solutions
+            let solutions_rows = solve_rows(pattern.view());
+            let solutions_columns = solve_rows(pattern.view().transpose());
+            if solutions_rows.is_empty() && solutions_columns.is_empty() {  // this flip yields no solutions
+                continue;
+            }
+
+            let new_solution = solutions_rows
+                .into_iter()
+                .map(Reflection::Row)
+                .chain(solutions_columns.into_iter().map(Reflection::Column))
+                .find(|s| *s != first_solution);
+
+            if let Some(new_solution) = new_solution {
+                return new_solution;
+            }
+        }
+    }
+
+    panic!("No smudge found");
+}
+
+// Patterns are separated by empty lines
+fn parse_patterns(lines: &[String]) -> Result<Vec<Grid<u8>>, ParseError> {
+    let mut patterns = Vec::new();
+    let mut first_line = 0;
+    for pattern in lines.split(String::is_empty) {
+        for (index, row) in pattern.iter().enumerate() {
+            if let Some((i, c)) = row.char_indices().find(|&(_, c)| c != '.' && c != '#') {
+                return Err(ParseError::at(first_line + index, row, &row[i..i + c.len_utf8()], "unknown character"));
+            }
+        }
+        let grid = Grid::parse(pattern).map_err(|error| ParseError { line: error.line + first_line, ..error })?;
+        first_line += pattern.len() + 1;
+        patterns.push(grid);
+    }
+
+    Ok(patterns)
+}
+
+pub const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<u8>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    // Patterns are separated by empty lines
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_patterns(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.iter().map(|pattern| find_reflection(pattern).summary()).sum()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        input.iter().map(|pattern| find_smudged_reflection(pattern).summary()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day13>(EXAMPLE);
+        assert_eq!(Day13::part1(&input), 405);
+        assert_eq!(Day13::part2(&input), 400);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::cycle::find_cycle_hashed;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Ground,
+    Ball,
+    Rock,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'.' => Ok(Tile::Ground),
+            b'O' => Ok(Tile::Ball),
+            b'#' => Ok(Tile::Rock),
+            _ => Err("invalid character in map"),
+        }
+    }
+}
+
+// Roll all balls north, the other directions are a rotation of the map away
+fn tilt(map: &mut Grid<Tile>) {
+    for x in 0..map.width as i64 {
+        // Row where the next ball stops
+        let mut free = 0;
+        for y in 0..map.height as i64 {
+            match map[Point::new(x, y)] {
+                Tile::Rock => free = y + 1,
+                Tile::Ball => {
+                    map[Point::new(x, y)] = Tile::Ground;
+                    map[Point::new(x, free)] = Tile::Ball;
+                    free += 1;
+                }
+                Tile::Ground => (),
+            }
+        }
+    }
+}
+
+// Roll north, west, south and east. After each tilt the map is rotated so that the next direction is north.
+fn spin_cycle(map: &Grid<Tile>) -> Grid<Tile> {
+    let mut map = map.clone();
+    for _ in 0..4 {
+        tilt(&mut map);
+        map = map.rotate_clockwise();
+    }
+    map
+}
+
+// Each ball is worth as many points as its distance to the south edge
+fn load(map: &Grid<Tile>) -> u32 {
+    let mut result = 0;
+    for (index, line) in map.rows().rev().enumerate() {
+        // Each ball in this line is worth index+1 points
+        for tile in line {
+            if *tile == Tile::Ball {
+                result += index as u32 + 1;
+            }
+        }
+    }
+    result
+}
+
+// Part 1 - Roll all balls north
+fn part_1(map: &Grid<Tile>) -> u32 {
+    let mut map = map.clone();
+    tilt(&mut map);
+    load(&map)
+}
+
+// Part 2 - Perfom 1_000_000_000 cycles, each cycle being a full roll North->West->South->East
+fn part_2(map: &Grid<Tile>) -> u32 {
+    // The maps soon repeat, so only the first cycles are simulated, remembering just a hash of each map
+    let cycle = find_cycle_hashed(map, spin_cycle);
+    load(&cycle.state_after(map, spin_cycle, 1_000_000_000))
+}
+
+pub const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<Tile>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Grid::try_parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part_2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day14>(EXAMPLE);
+        assert_eq!(Day14::part1(&input), 136);
+        assert_eq!(Day14::part2(&input), 64);
+    }
+}
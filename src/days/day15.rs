@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+
+use crate::error::{get_line, parse_number, ParseError};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Lens {
+    label: String,
+    power: u8,
+}
+
+fn hash(input: &str) -> u8 {
+    let mut result = 0;
+    input.chars().for_each(|c| {
+        result += c as u16;
+        result *= 17;
+        result %= 256;
+    });
+    result as u8
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operation {
+    Remove,
+    Insert(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    text: String,  // The step as written, which part 1 hashes
+    label: String,
+    operation: Operation,
+}
+
+// Part 1 - Sum of all hashes
+fn sum_of_hashes(steps: &[Step]) -> u32 {
+    steps.iter().map(|step| {
+        hash(&step.text) as u32
+    }).sum::<u32>()
+}
+
+// Part 2 - Boxes
+fn focusing_power(steps: &[Step]) -> u32 {
+    let mut boxes: Vec<VecDeque<Lens>> = vec![VecDeque::new(); 256];
+    steps.iter().for_each(|step| {
+        let hash = hash(&step.label) as usize;
+        let index = boxes[hash].iter().position(|l| l.label == step.label);
+
+        match step.operation {
+            Operation::Insert(power) => {  // Put or replace lens in box
+                let lens = Lens { label: step.label.clone(), power };
+                if let Some(index) = index {
+                    boxes[hash][index] = lens;  // Replace old lens in the same place
+                } else {
+                    boxes[hash].push_back(lens);
+                }
+            }
+            Operation::Remove => {  // Remove lens from box
+                if let Some(index) = index {
+                    boxes[hash].remove(index);
+                }
+            }
+        }
+    });
+
+    // Calculate result
+    let mut r2 = 0;
+    boxes.iter().enumerate().for_each(|(i, box_)| {
+        box_.iter().enumerate().for_each(|(j, lens)| {
+            r2 += (1+i as u32) * (1+j as u32) * (lens.power as u32);
+        });
+    });
+
+    r2
+}
+
+pub const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    // Input is a single line
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        let line = get_line(lines, 0, "initialization sequence")?;
+        line.split(',').map(|step| {
+            // Either "label-" or "label=power"
+            let (label, operation) = if let Some((label, power)) = step.split_once('=') {
+                (label, Operation::Insert(parse_number(0, line, power)?))
+            } else if let Some(label) = step.strip_suffix('-') {
+                (label, Operation::Remove)
+            } else {
+                return Err(ParseError::at(0, line, step, "expected '=' or '-'"));
+            };
+            Ok(Step { text: step.to_owned(), label: label.to_owned(), operation })
+        }).collect()
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        sum_of_hashes(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        focusing_power(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day15>(EXAMPLE);
+        assert_eq!(Day15::part1(&input), 1320);
+        assert_eq!(Day15::part2(&input), 145);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::bit_grid::BitGrid;
+use crate::util::grid::Grid;
+use crate::util::point::{Direction, Point};
+
+#[derive(Clone)]
+pub enum Tile {
+    Empty,
+    VSplitter,
+    HSplitter,
+    SlashMirror,
+    BackslashMirror,
+}
+
+impl TryFrom<u8> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'|' => Ok(Tile::VSplitter),
+            b'-' => Ok(Tile::HSplitter),
+            b'/' => Ok(Tile::SlashMirror),
+            b'\\' => Ok(Tile::BackslashMirror),
+            b'.' => Ok(Tile::Empty),
+            _ => Err("unknown tile"),
+        }
+    }
+}
+
+// Tiles energized by a beam entering the grid at the given tile, going in the given direction
+fn energize(grid: &Grid<Tile>, initial_beam: (Point, Direction)) -> BitGrid {
+    // Tiles entered by a beam going in each direction, indexed by `Direction as usize`
+    let mut energized_from: [BitGrid; 4] = std::array::from_fn(|_| BitGrid::new(grid.width, grid.height));
+    let mut beams = vec![initial_beam];
+
+    while let Some((mut point, mut direction)) = beams.pop() {
+        while grid.contains(point) {
+            if !energized_from[direction as usize].insert(point) {  // we've been here before
+                break;
+            }
+
+            match &grid[point] {
+                // Found | or - across its length, split into both directions along the splitter
+                Tile::VSplitter | Tile::HSplitter if direction.is_vertical() != matches!(grid[point], Tile::VSplitter) => {
+                    let (a, b) = (direction.turn_left(), direction.turn_right());
+                    beams.push((point + a, a));
+                    beams.push((point + b, b));
+                    break;
+                },
+                Tile::Empty | Tile::VSplitter | Tile::HSplitter => (),  // Continue in same direction
+                // Found /, which turns vertical beams right and horizontal beams left
+                Tile::SlashMirror => {
+                    direction = if direction.is_vertical() { direction.turn_right() } else { direction.turn_left() };
+                },
+                // Found \, the other way around
+                Tile::BackslashMirror => {
+                    direction = if direction.is_vertical() { direction.turn_left() } else { direction.turn_right() };
+                },
+            }
+
+            point += direction;
+        }
+    }
+
+    let mut energized = BitGrid::new(grid.width, grid.height);
+    for tiles in &energized_from {
+        energized.union_with(tiles);
+    }
+    energized
+}
+
+// Number of energized tiles
+fn process(grid: &Grid<Tile>, initial_beam: (Point, Direction)) -> u32 {
+    energize(grid, initial_beam).count_ones() as u32
+}
+
+// Part 2 - Beam can start from any point in the border, find maximum value of energied tiles
+fn most_energized(grid: &Grid<Tile>) -> u32 {
+    let (width, height) = (grid.width as i64, grid.height as i64);
+    let top = (0..width).map(|x| (Point::new(x, 0), Direction::Down));  // Along top border
+    let bottom = (0..width).map(|x| (Point::new(x, height - 1), Direction::Up));  // Along bottom border
+    let left = (0..height).map(|y| (Point::new(0, y), Direction::Right));  // Along left border
+    let right = (0..height).map(|y| (Point::new(width - 1, y), Direction::Left));  // Along right border
+
+    top.chain(bottom).chain(left).chain(right).map(|b| process(grid, b)).max().unwrap()
+}
+
+pub const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<Tile>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Grid::try_parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        process(input, (Point::new(0, 0), Direction::Right))
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        most_energized(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+    use crate::util::render::Style;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day16>(EXAMPLE);
+        assert_eq!(Day16::part1(&input), 46);
+        assert_eq!(Day16::part2(&input), 51);
+    }
+
+    #[test]
+    fn test_energized_tiles() {
+        let grid = parse_example::<Day16>(EXAMPLE);
+        let energized = energize(&grid, (Point::new(0, 0), Direction::Right));
+        let render = grid.render(|_| '.').highlight(energized.iter(), Style::new().glyph('#'));
+        let expected = "\
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..
+";
+        assert_eq!(render.to_string(), expected);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::grid::Grid;
+use crate::util::image::Image;
+use crate::util::point::*;
+use crate::util::search::dijkstra;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Node {
+    position: Point,
+    direction: Direction,
+    forward_streak: u32,
+}
+
+fn parse_grid(input: &[String]) -> Result<Grid<u32>, ParseError> {
+    for (index, line) in input.iter().enumerate() {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::at(index, line, &line[i..i + c.len_utf8()], "expected a digit"));
+        }
+    }
+    Grid::parse_with(input, |b| (b - b'0') as u32)
+}
+
+// Blocks the crucible goes through from the top left to the bottom right corner, and their total heat loss
+fn route(grid: &Grid<u32>, min_streak: u32, max_streak: u32) -> (Vec<Point>, u32) {
+    // The crucible can turn left or right once it went at least min_streak blocks forward,
+    // and go forward until it went max_streak blocks
+    let successors = |node: &Node| {
+        let mut successors = Vec::new();
+        if node.forward_streak >= min_streak {
+            for direction in [node.direction.turn_left(), node.direction.turn_right()] {
+                successors.push(Node { position: node.position + direction, direction, forward_streak: 1 });
+            }
+        }
+        if node.forward_streak != max_streak {
+            successors.push(Node {
+                position: node.position + node.direction,
+                forward_streak: node.forward_streak + 1,
+                ..*node
+            });
+        }
+
+        // The cost of a move is the heat lost in the block it enters
+        successors
+            .into_iter()
+            .filter_map(|n| grid.get(n.position).map(|&cost| (n, cost)))
+            .collect::<Vec<_>>()
+    };
+
+    let goal = Point::new(grid.width as i64 - 1, grid.height as i64 - 1);
+    let result = dijkstra(
+        Node {
+            position: ORIGIN,
+            direction: Direction::Right,
+            forward_streak: 0,
+        },
+        successors,
+        |n| n.position == goal && n.forward_streak >= min_streak,
+    ).unwrap();
+
+    let blocks = result.0.iter().map(|n| n.position).collect();
+    (blocks, result.1)
+}
+
+fn solve(grid: &Grid<u32>, min_streak: u32, max_streak: u32) -> u32 {
+    route(grid, min_streak, max_streak).1
+}
+
+/// Picture of the map with the route of the crucible, or of the ultra crucible, in red. Blocks that lose
+/// more heat are darker.
+pub fn draw_route(grid: &Grid<u32>, ultra: bool) -> Image {
+    let (min_streak, max_streak) = if ultra { (4, 10) } else { (0, 3) };
+    let (blocks, _) = route(grid, min_streak, max_streak);
+    grid.to_image(|&heat_loss| [255 - 25 * heat_loss as u8; 3]).path(blocks, [255, 0, 0])
+}
+
+pub const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+// Example for part 2
+pub const EXAMPLE_2: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE), ("example_2", EXAMPLE_2)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse_grid(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        solve(input, 0, 3)
+    }
+
+    // Ultra crucibles move at least 4 and at most 10 blocks in the same direction
+    fn part2(input: &Self::Input) -> u32 {
+        solve(input, 4, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day17>(EXAMPLE);
+        assert_eq!(Day17::part1(&input), 102);
+        assert_eq!(Day17::part2(&input), 94);
+    }
+
+    #[test]
+    fn test_route() {
+        let grid = parse_example::<Day17>(EXAMPLE);
+        let (blocks, heat_loss) = route(&grid, 0, 3);
+        assert_eq!(blocks.first(), Some(&Point::new(0, 0)));
+        assert_eq!(blocks.last(), Some(&Point::new(12, 12)));
+        assert_eq!(blocks.iter().skip(1).map(|&block| grid[block]).sum::<u32>(), heat_loss);
+
+        let image = draw_route(&grid, false);
+        assert_eq!(image.pixel(Point::new(2, 0)), [255, 0, 0]);
+        assert_eq!(image.pixel(Point::new(0, 1)), [180; 3]);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(Day17::part2(&parse_example::<Day17>(EXAMPLE_2)), 71);
+    }
+}
@@ -0,0 +1,148 @@
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+use crate::util::point::*;
+use itertools::Itertools;
+
+pub struct Instruction {
+    direction: Direction,
+    steps: i64,
+}
+
+fn solve(input: &[Instruction]) -> u64 {
+    let mut vertices = vec![ORIGIN];
+    for instruction in input {
+        let last = vertices[vertices.len() - 1];
+        vertices.push(last + Point::from(instruction.direction) * instruction.steps);
+    }
+
+    // Shoelace formula for calculating area of polygon given vertices
+    // Also calculate perimeter
+    let mut perimeter = 0;
+    let mut area = 0;
+    for (pn, pn1) in vertices.iter().tuple_windows() {
+        area += (pn.y + pn1.y) * (pn.x - pn1.x);
+        perimeter += pn.manhattan_distance(pn1) as u64;
+    }
+    let area = (area.abs()/2) as u64;
+
+    // Apply Pick's theorem to the area of the polygon
+    // A = i + b/2 - 1
+    // i = A - b/2 + 1
+    let interior_points = area - perimeter/2 + 1;
+
+    // Total number of point is i + b
+    interior_points + perimeter
+}
+
+// Splits a line such as "R 6 (#70c710)" into its direction, steps and 6 hexadecimal digits of the color
+fn fields(index: usize, line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (direction, steps, color) = line
+        .split_whitespace()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::at(index, line, line, "expected direction, steps and color"))?;
+    let hex = color
+        .strip_prefix("(#")
+        .and_then(|c| c.strip_suffix(')'))
+        .filter(|c| c.len() == 6 && c.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| ParseError::at(index, line, color, "expected color as (#rrggbb)"))?;
+    Ok((direction, steps, hex))
+}
+
+fn parse(input: &[String]) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+
+    for (index, line) in input.iter().enumerate() {
+        // The color only matters in part 2, where it holds the instruction
+        let (direction, steps, _) = fields(index, line)?;
+        let direction = match direction.as_bytes() {
+            &[byte @ (b'U' | b'D' | b'L' | b'R')] => Direction::try_from(byte).unwrap(),
+            _ => return Err(ParseError::at(index, line, direction, "invalid direction")),
+        };
+        let steps = parse_number::<i64>(index, line, steps)?;
+
+        instructions.push(Instruction { direction, steps });
+    }
+
+    Ok(instructions)
+}
+
+fn parse2(input: &[String]) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = Vec::new();
+
+    for (index, line) in input.iter().enumerate() {
+        let (steps, direction) = fields(index, line)?.2.split_at(5);
+        let steps = i64::from_str_radix(steps, 16).unwrap();
+        // The last digit is the direction, from 0 for right to 3 for up
+        let direction = match direction.as_bytes() {
+            &[byte @ b'0'..=b'3'] => Direction::try_from(byte).unwrap(),
+            _ => return Err(ParseError::at(index, line, direction, "invalid direction code")),
+        };
+
+        instructions.push(Instruction { direction, steps });
+    }
+
+    Ok(instructions)
+}
+
+pub const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+pub const EXAMPLE_2: &str = "\
+R 6 (#000000)
+D 2 (#000000)
+L 6 (#000000)
+U 2 (#000000)";
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    // Part 2 reads the instructions from the hexadecimal color codes instead
+    type Input = (Vec<Instruction>, Vec<Instruction>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE), ("example_2", EXAMPLE_2)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok((parse(lines)?, parse2(lines)?))
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        solve(&input.0)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        solve(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day18>(EXAMPLE);
+        assert_eq!(Day18::part1(&input), 62);
+        assert_eq!(Day18::part2(&input), 952408144115);
+    }
+
+    #[test]
+    fn test_rectangle() {
+        assert_eq!(Day18::part1(&parse_example::<Day18>(EXAMPLE_2)), 21);
+    }
+}
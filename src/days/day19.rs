@@ -0,0 +1,307 @@
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+use crate::util::interval::{Interval, IntervalSet};
+use std::collections::HashMap;
+use regex::Regex;
+
+// Every rating is between 1 and 4000
+const RATINGS: Interval = Interval::closed(1, 4000);
+
+// Ratings of a single part
+#[derive(Debug)]
+pub struct Part {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32,
+}
+
+impl Part {
+    fn rating(&self, variable: &str) -> u32 {
+        match variable {
+            "x" => self.x,
+            "m" => self.m,
+            "a" => self.a,
+            "s" => self.s,
+            _ => panic!("Invalid variable"),
+        }
+    }
+}
+
+// Ranges of ratings of many parts at once
+#[derive(Debug, Clone)]
+struct PartRange {
+    x: IntervalSet,
+    m: IntervalSet,
+    a: IntervalSet,
+    s: IntervalSet,
+}
+
+impl PartRange {
+    fn combinations(&self) -> u64 {
+        (self.x.len() * self.m.len() * self.a.len() * self.s.len()) as u64
+    }
+
+    fn rating_mut(&mut self, variable: &str) -> &mut IntervalSet {
+        match variable {
+            "x" => &mut self.x,
+            "m" => &mut self.m,
+            "a" => &mut self.a,
+            "s" => &mut self.s,
+            _ => panic!("Invalid variable"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    variable: Option<String>,
+    operation: Option<char>,
+    value: Option<u32>,
+    destination: String,
+}
+
+impl Rule {
+    // Whether the part is sent to this rule's destination
+    fn applies_to(&self, part: &Part) -> bool {
+        let (Some(variable), Some(operation), Some(value)) = (&self.variable, self.operation, self.value) else {
+            return true;  // A, R or other Rule's label directly
+        };
+
+        let rating = part.rating(variable);
+        match operation {
+            '<' => rating < value,
+            '>' => rating > value,
+            _ => panic!("Invalid operation"),
+        }
+    }
+}
+
+type Workflow = Vec<Rule>;
+type Workflows = HashMap<String, Workflow>; // label -> rules
+
+fn parse(input: &[String]) -> Result<(Vec<Part>, Workflows), ParseError> {
+    let mut parts = Vec::new();
+    let mut workflows = HashMap::new();
+    let mut destinations = Vec::new();  // checked once all workflows are known
+    let part_re = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
+    let workflow_re = Regex::new(r"^(\w+)\{(.*)\}$").unwrap();
+
+    for (index, line) in input.iter().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        } else if line.starts_with('{') {
+            // Parse part
+            let captures = part_re
+                .captures(line)
+                .ok_or_else(|| ParseError::at(index, line, line, "expected part as {x=..,m=..,a=..,s=..}"))?;
+            let [x, m, a, s] = [1, 2, 3, 4].map(|i| parse_number::<u32>(index, line, captures.get(i).unwrap().as_str()));
+            parts.push(Part { x: x?, m: m?, a: a?, s: s? });
+            continue;
+        }
+
+        // Parse workflow
+        let mut workflow: Workflow = Vec::new();
+        let captures = workflow_re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(index, line, line, "expected workflow as label{rules}"))?;
+
+        let label = captures.get(1).unwrap().as_str().to_string();
+        let rules_str = captures.get(2).unwrap().as_str();
+        for rule in rules_str.split(',') {
+            let Some((condition, destination)) = rule.split_once(':') else {
+                // returns other Rule's label directly, A or R
+                destinations.push((index, line, rule));
+                workflow.push(Rule {
+                    variable: None,
+                    operation: None,
+                    value: None,
+                    destination: rule.to_owned(),
+                });
+                continue;
+            };
+
+            let variable = condition.get(..1).filter(|v| matches!(*v, "x" | "m" | "a" | "s"))
+                .ok_or_else(|| ParseError::at(index, line, condition, "expected one of x, m, a or s"))?;
+            let operation = condition[1..].chars().next().filter(|o| matches!(o, '<' | '>'))
+                .ok_or_else(|| ParseError::at(index, line, &condition[1..], "expected '<' or '>'"))?;
+            let compare_value = parse_number::<u32>(index, line, &condition[2..])?;
+
+            destinations.push((index, line, destination));
+            workflow.push(Rule {
+                variable: Some(variable.to_string()),
+                operation: Some(operation),
+                value: Some(compare_value),
+                destination: destination.to_owned(),
+            });
+        }
+
+        if workflow.last().is_some_and(|rule| rule.variable.is_some()) {
+            return Err(ParseError::at(index, line, rules_str, "last rule must not have a condition"));
+        }
+        workflows.insert(label, workflow);
+    }
+
+    for (index, line, destination) in destinations {
+        if destination != "A" && destination != "R" && !workflows.contains_key(destination) {
+            return Err(ParseError::at(index, line, destination, "unknown workflow"));
+        }
+    }
+    if !workflows.contains_key("in") {
+        return Err(ParseError::missing_line(input.len(), "expected workflow 'in'"));
+    }
+
+    Ok((parts, workflows))
+}
+
+fn apply_workflow<'a>(part: &Part, workflow: &'a Workflow) -> &'a str {
+    for rule in workflow {
+        if rule.applies_to(part) {
+            return &rule.destination;
+        }
+    }
+    unreachable!()  // final rule of workflow is always a label/reject/accept
+}
+
+// Part 1 - Sum the ratings of all accepted parts
+fn sum_of_accepted(parts: &[Part], workflows: &Workflows) -> u32 {
+    parts.iter().filter(|part| {
+        let mut workflow = workflows.get("in").unwrap();
+        loop {
+            let next_rule = apply_workflow(part, workflow);
+            if next_rule == "A" {
+                return true;
+            } else if next_rule == "R" {
+                return false;
+            }
+            workflow = workflows.get(next_rule).unwrap();
+        }
+    }).fold(0, |acc, f| {
+        acc + f.x + f.m + f.a + f.s
+    })
+}
+
+// Recursively calculate the possible part ranges that will yield in approved parts
+fn calculate(workflow: &Workflow, part_range: PartRange, workflows: &Workflows) -> Vec<PartRange> {
+    let mut result = Vec::new();
+    let mut current_parts_range = part_range;
+
+    for rule in workflow {
+        if rule.variable.is_none() {
+            // A, R or other Rule's label directly
+            if rule.destination == "A" {  // this part range got approved
+                result.push(current_parts_range.clone());
+            } else if rule.destination == "R" {  // this part range got rejected
+                break;
+            } else {
+                // other Rule's label directly
+                let next_workflow = workflows.get(&rule.destination).unwrap();
+                result.append(&mut calculate(next_workflow, current_parts_range.clone(), workflows));
+            }
+
+            break;
+        }
+
+        let variable = rule.variable.as_ref().unwrap();
+        let operation = rule.operation.unwrap();
+        let value = rule.value.unwrap();
+
+        // x > 1024
+        let comparison_range = IntervalSet::from(match operation {
+            '>' => Interval::new(value as i64 + 1, RATINGS.end),
+            '<' => Interval::new(RATINGS.start, value as i64),
+            _ => panic!("Invalid operation"),
+        });
+
+        // Parts to be sent to the next workflow, the others go on to the next rule
+        let mut passing_part_range = current_parts_range.clone();
+        let current = current_parts_range.rating_mut(variable);
+        let passing = current.intersection(&comparison_range);
+        if passing.is_empty() {
+            continue;  // didn't pass, skip to next rule
+        }
+        *current = current.difference(&comparison_range);
+        *passing_part_range.rating_mut(variable) = passing;
+
+        // Recursively calculate the possible part ranges that will yield in approved parts
+        if &rule.destination == "A" {
+            result.push(passing_part_range);
+        } else if &rule.destination == "R" {
+            continue;
+        } else {
+            let next_workflow = workflows.get(&rule.destination).unwrap();
+            result.append(&mut calculate(next_workflow, passing_part_range, workflows));
+        }
+    }
+
+    result
+}
+
+// Part 2 - Count all combinations of ratings that would be accepted
+fn accepted_combinations(input: &Workflows) -> u64 {
+    let ratings = IntervalSet::from(RATINGS);
+    let part = PartRange {
+        x: ratings.clone(),
+        m: ratings.clone(),
+        a: ratings.clone(),
+        s: ratings,
+    };
+
+    let ranges = calculate(input.get("in").unwrap(), part, input);
+    ranges.iter().map(|r| r.combinations()).sum()
+}
+
+pub const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<Part>, Workflows);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        sum_of_accepted(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        accepted_combinations(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day19>(EXAMPLE);
+        assert_eq!(Day19::part1(&input), 19114);
+        assert_eq!(Day19::part2(&input), 167409079868000);
+    }
+}
@@ -1,4 +1,6 @@
-use advent_of_code_2023::read_lines_as_vec;
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::math::first_common;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -163,19 +165,45 @@ impl Module {
     }
 }
 
-fn parse(input: &[String]) -> HashMap<String, Module> {
-    let mut modules = HashMap::new();
+// Module as described in the input, before any state is attached to it
+pub struct ModuleConfig {
+    function: String,
+    destinations: Vec<String>,
+}
+
+fn parse(input: &[String]) -> Result<Vec<ModuleConfig>, ParseError> {
+    let mut configs = Vec::new();
 
-    for line in input {
-        let mut parts = line.trim().split(" -> ");
-        let function = parts.next().unwrap();
-        let destinations = parts
-            .next()
-            .unwrap()
+    for (index, line) in input.iter().enumerate() {
+        let (function, destinations) = line
+            .trim()
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(index, line, line, "expected 'module -> destinations'"))?;
+        if function != "broadcaster" && !(function.starts_with(['%', '&']) && function.len() > 1) {
+            return Err(ParseError::at(index, line, function, "unknown module type"));
+        }
+        let destinations = destinations
             .split(',')
             .map(|d| d.trim().to_owned())
             .collect::<Vec<_>>();
 
+        configs.push(ModuleConfig { function: function.to_owned(), destinations });
+    }
+
+    if !configs.iter().any(|config| config.function == "broadcaster") {
+        return Err(ParseError::missing_line(input.len(), "expected a broadcaster module"));
+    }
+
+    Ok(configs)
+}
+
+fn build(configs: &[ModuleConfig]) -> HashMap<String, Module> {
+    let mut modules = HashMap::new();
+
+    for config in configs {
+        let function = config.function.as_str();
+        let destinations = config.destinations.clone();
+
         if function == "broadcaster" {
             // special name
             let module = Module::new(
@@ -201,7 +229,7 @@ fn parse(input: &[String]) -> HashMap<String, Module> {
                 destinations,
                 Box::new(Conjunction::new()),
             ),
-            _ => panic!("Unknown module type: {}", mod_type),
+            _ => unreachable!("module types are checked when parsing"),
         };
 
         modules.insert(mod_name.to_owned(), module);
@@ -230,54 +258,34 @@ fn parse(input: &[String]) -> HashMap<String, Module> {
     modules
 }
 
-fn solve(mut modules: HashMap<String, Module>, n_button_presses: u64, stop_on_rx: bool) -> u64 {
+// Pushes the button once and processes all resulting events, calling `observe` on each of them
+fn press_button<F: FnMut(&Event)>(modules: &mut HashMap<String, Module>, mut observe: F) {
     let mut event_queue = VecDeque::new();
 
-    let mut button_presses = 1;
-    loop {
-        if stop_on_rx && button_presses % 100000 == 0 {
-            println!("button press {}", button_presses);
-        }
-
-        // Push button press event as first event
-        event_queue.push_back(Event {
-            origin: "god".to_string(),
-            destination: "button".to_string(),
-            pulse: Pulse::Low, // for the button it doesn't matter
-        });
-
-        while let Some(event) = event_queue.pop_front() {
-            if event.destination == "ll" && event.pulse == Pulse::High {
-                // Only source for "rx" is "ll", which is a conjunction.
-                // "ll" needs to receive high pulse from all it's inputs to send a low pulse to "rx"
-                // Manually check for this condition and return the number of button presses for each of "ll"'s inputs
-                // to send it a high pulse.
-                // Since it has 4 inputs, get the lcm of the number of button presses for each input and multiply them
-                // to obtain the number of button presses where all inputs simultaneously send a high pulse.
-                // hacky but works
-                println!("ll received high pulse at button press {} from {}", button_presses, event.origin);
-            }
-
-            if stop_on_rx && event.destination == "rx" && event.pulse == Pulse::Low {
-                println!("rx received low pulse at button press {}", button_presses);
-                return button_presses;
-            }
+    // Push button press event as first event
+    event_queue.push_back(Event {
+        origin: "god".to_string(),
+        destination: "button".to_string(),
+        pulse: Pulse::Low, // for the button it doesn't matter
+    });
 
-            let module = modules.get_mut(&event.destination);
-            if module.is_none() {
-                // destination is untyped, ignore
-                continue;
-            }
-            if let Some(events) = module.unwrap().process_input(&event) {
-                event_queue.extend(events);
-            }
-        }
+    while let Some(event) = event_queue.pop_front() {
+        observe(&event);
 
-        button_presses += 1;
-        if stop_on_rx || button_presses <= n_button_presses {
+        let module = modules.get_mut(&event.destination);
+        if module.is_none() {
+            // destination is untyped, ignore
             continue;
         }
-        break;
+        if let Some(events) = module.unwrap().process_input(&event) {
+            event_queue.extend(events);
+        }
+    }
+}
+
+fn solve(mut modules: HashMap<String, Module>, n_button_presses: u64) -> u64 {
+    for _ in 0..n_button_presses {
+        press_button(&mut modules, |_| ());
     }
 
     let mut total_low_pulses = 0;
@@ -290,28 +298,93 @@ fn solve(mut modules: HashMap<String, Module>, n_button_presses: u64, stop_on_rx
     total_low_pulses * total_high_pulses
 }
 
-fn main() {
-    let _lines = read_lines_as_vec("inputs/20.txt").unwrap();
-    let _example1 = r#"broadcaster -> a, b, c
-    %a -> b
-    %b -> c
-    %c -> inv
-    &inv -> a"#
-        .lines()
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
-    let _example2 = r#"broadcaster -> a
-    %a -> inv, con
-    &inv -> b
-    %b -> con
-    &con -> output"#
-        .lines()
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
-
-    let result = solve(parse(&_lines), 1000, false);
-    println!("Result: {}", result);
-
-    // Solve part 2 - find the number of button presses that will cause rx to receive a low pulse
-    solve(parse(&_lines), 0, true);
+// Part 2 - find the number of button presses that will cause rx to receive a low pulse
+// Fails on inputs without "rx", such as the examples
+fn presses_until_rx(mut modules: HashMap<String, Module>) -> Result<u64, String> {
+    // Only source for "rx" is a conjunction ("ll" in the input).
+    // It needs to receive high pulse from all it's inputs to send a low pulse to "rx".
+    // Each input sends it a high pulse periodically, so record the first two button presses where it does,
+    // and find the first press where all of them do with the Chinese Remainder Theorem.
+    let feeder = modules
+        .values()
+        .find(|module| module.destinations.iter().any(|d| d == "rx"))
+        .ok_or("no module sends pulses to rx")?
+        .name
+        .clone();
+    let n_inputs = modules
+        .values()
+        .filter(|module| module.destinations.contains(&feeder))
+        .count();
+
+    let mut high_pulses: HashMap<String, Vec<i64>> = HashMap::new();
+    let mut button_presses = 0;
+    while high_pulses.len() < n_inputs || high_pulses.values().any(|presses| presses.len() < 2) {
+        button_presses += 1;
+        press_button(&mut modules, |event| {
+            if event.destination == feeder && event.pulse == Pulse::High {
+                let presses = high_pulses.entry(event.origin.clone()).or_default();
+                if presses.last() != Some(&button_presses) {
+                    presses.push(button_presses);
+                }
+            }
+        });
+    }
+
+    let cycles = high_pulses
+        .values()
+        .map(|presses| (presses[0], presses[1] - presses[0]))
+        .collect::<Vec<_>>();
+    let presses = first_common(&cycles).ok_or(format!("the inputs of {} never send a high pulse together", feeder))?;
+    Ok(presses as u64)
+}
+
+pub const EXAMPLE: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+pub const EXAMPLE_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<ModuleConfig>;
+    type Answer1 = u64;
+    type Answer2 = Result<u64, String>;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE), ("example_2", EXAMPLE_2)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        solve(build(input), 1000)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, String> {
+        presses_until_rx(build(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    // The examples have no rx module, so only part 1 can be checked
+    #[test]
+    fn test_example() {
+        assert_eq!(Day20::part1(&parse_example::<Day20>(EXAMPLE)), 32000000);
+        assert_eq!(Day20::part1(&parse_example::<Day20>(EXAMPLE_2)), 11687500);
+        // The examples have no rx
+        assert_eq!(Day20::part2(&parse_example::<Day20>(EXAMPLE)), Err("no module sends pulses to rx".to_string()));
+    }
 }
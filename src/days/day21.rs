@@ -0,0 +1,190 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::bit_grid::BitGrid;
+use crate::util::grid::Grid;
+use crate::util::point::*;
+use crate::util::search::grid_bfs;
+use std::collections::HashMap;
+
+fn parse(lines: &[String]) -> Result<(Grid<bool>, Point), ParseError> {
+    for (row, line) in lines.iter().enumerate() {
+        if let Some((column, c)) = line.char_indices().find(|&(_, c)| !".#S".contains(c)) {
+            return Err(ParseError::at(row, line, &line[column..column + c.len_utf8()], "invalid character"));
+        }
+    }
+
+    let tiles = Grid::parse(lines)?;
+    let start_node = tiles.find(&b'S');
+
+    // true is ground
+    // false is rocks
+    let grid = Grid::from_fn(tiles.width, tiles.height, |point| tiles[point] != b'#');
+
+    let last = lines.len() - 1;
+    let start_node = start_node.ok_or_else(|| ParseError::end_of_line(last, &lines[last], "no starting position 'S' found"))?;
+    Ok((grid, start_node))
+}
+
+// Returns a map of all reachable nodes in the map and their distance to the center
+fn solve(grid: &Grid<bool>, start_node: Point) -> HashMap<Point, u32> {
+    grid_bfs(grid, start_node, |&ground| ground)
+        .into_iter()
+        .map(|(node, distance)| (node, distance as u32))
+        .collect()
+}
+
+// Moves all positions at once: the plots reachable in one more step are the neighbors of the reachable ones
+fn part_1(grid: &Grid<bool>, start_node: Point, steps: u32) -> u32 {
+    let ground = BitGrid::from_grid(grid, |&ground| ground);
+    let mut reachable = BitGrid::new(grid.width, grid.height);
+    reachable.insert(start_node);
+
+    for _ in 0..steps {
+        let mut next = reachable.shifted(UP);
+        for direction in [DOWN, LEFT, RIGHT] {
+            next.union_with(&reachable.shifted(direction));
+        }
+        next.intersect_with(&ground);
+        reachable = next;
+    }
+
+    reachable.count_ones() as u32
+}
+
+// Based on https://github.com/villuna/aoc23/wiki/A-Geometric-solution-to-advent-of-code-2023,-day-21
+fn part_2(solution: &HashMap<Point, u32>, steps: u32, square_dimension: u32) -> u64 {
+    let parity = steps % 2;
+
+    let even_nodes = solution.iter().filter(|(_, cost)| **cost % 2 == 0).count() as u64;
+    let odd_nodes = solution.iter().filter(|(_, cost)| **cost % 2 == 1).count() as u64;
+
+    // n is the number of copies of the map we can fit in the steps
+    let n = (steps - square_dimension / 2) / square_dimension;
+    let n = n as u64;
+
+    let (n_full_even_squares, n_full_odd_squares) = if n.is_multiple_of(2) {
+        // even n means there are (n+1)^2 squares with same parity as starting square and n² squares with opposite parity
+        if parity == 0 {  // starting square is even
+            ((n+1).pow(2), n.pow(2))
+        } else {  // starting square is odd
+            (n.pow(2), (n+1).pow(2))
+        }
+    } else {
+        // odd n means there are n² squares with same parity as starting square and (n+1)^2 squares with opposite parity
+        if parity == 0 {  // starting square is even
+            (n.pow(2), (n+1).pow(2))
+        } else {  // starting square is odd
+            ((n+1).pow(2), n.pow(2))
+        }
+    };
+
+    let even_half_nodes = solution.iter().filter(|(_, cost)| **cost % 2 == 0 && **cost > square_dimension/2).count() as u64;
+    let odd_half_nodes = solution.iter().filter(|(_, cost)| **cost % 2 == 1 && **cost > square_dimension/2).count() as u64;
+
+    // Number of each half nodes depend on parity
+    let (n_even_hal, n_odd_half) = if parity == 0 {
+        (n+1, n)
+    } else {
+        (n, n+1)
+    };
+
+    // Wheter we subtract or add the half nodes depends on parity too
+    if parity == 0 {
+        even_nodes * n_full_even_squares + odd_nodes * n_full_odd_squares - n_even_hal * even_half_nodes + n_odd_half * odd_half_nodes
+    } else {
+        even_nodes * n_full_even_squares + odd_nodes * n_full_odd_squares + n_even_hal * even_half_nodes - n_odd_half * odd_half_nodes
+    }
+}
+
+pub const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Grid<bool>, Point);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let (grid, start_node) = input;
+        part_1(grid, *start_node, 64)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        let (grid, start_node) = input;
+        part_2(&solve(grid, *start_node), 26501365, grid.height as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+    use std::collections::HashSet;
+
+    // Number of plots reachable in exactly `steps` steps on the infinitely repeated map, by walking it step by step.
+    // Too slow for part 2 itself but makes no assumption on the map.
+    fn simulate_tiled(grid: &Grid<bool>, start_node: Point, steps: u32) -> u64 {
+        let tiled = grid.tiled();
+        let mut seen = HashSet::from([start_node]);
+        let mut frontier = vec![start_node];
+        // Plots reached at an even and odd number of steps, a plot reached in n steps is also reached in n + 2
+        let mut reached = [1, 0];
+
+        for step in 1..=steps {
+            frontier = frontier
+                .iter()
+                .flat_map(|&node| tiled.orthogonal_neighbors_where(node, |&ground| ground).map(|(neighbor, _)| neighbor))
+                .filter(|&neighbor| seen.insert(neighbor))
+                .collect();
+            reached[step as usize % 2] += frontier.len() as u64;
+        }
+
+        reached[steps as usize % 2]
+    }
+
+    // The example is for 6 steps. Part 2 relies on the empty row and column through the start of the
+    // real input, which the example does not have.
+    #[test]
+    fn test_example() {
+        let (grid, start_node) = parse_example::<Day21>(EXAMPLE);
+        assert_eq!(part_1(&grid, start_node, 6), 16);
+    }
+
+    #[test]
+    fn test_simulate_tiled() {
+        let (grid, start_node) = parse_example::<Day21>(EXAMPLE);
+        assert_eq!(simulate_tiled(&grid, start_node, 6), 16);
+        assert_eq!(simulate_tiled(&grid, start_node, 10), 50);
+        assert_eq!(simulate_tiled(&grid, start_node, 50), 1594);
+        assert_eq!(simulate_tiled(&grid, start_node, 100), 6536);
+    }
+
+    // The geometric solution matches walking the repeated map on a map with the shape it expects, as long as
+    // the number of copies of the map walked through is even like in the real input (26501365 = 65 + 131 * 202300)
+    #[test]
+    fn test_part_2_against_simulation() {
+        let lines = [".....", ".#.#.", "..S..", ".#...", "....."].map(String::from);
+        let (grid, start_node) = Day21::parse(&lines).unwrap();
+        for steps in [12, 22, 32] {
+            assert_eq!(part_2(&solve(&grid, start_node), steps, 5), simulate_tiled(&grid, start_node, steps));
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+use crate::util::grid3::Grid3;
+use crate::util::point3::{Cuboid, Point3, BELOW, ORIGIN_3D};
+use std::{vec, collections::{HashSet, VecDeque}};
+
+// Moves the block so that its bottom is at height z
+fn set_height(block: &mut Cuboid, z: i64) {
+    *block = block.translated(Point3::new(0, 0, z - block.min.z));
+}
+
+fn calculate_chain(block: usize, supports: &[Vec<usize>], supported_by: &[Vec<usize>]) -> u32 {
+    // Stores blocks marked for removal
+    let mut to_remove = HashSet::new();
+    to_remove.insert(block);
+
+    let mut blocks_to_analyze = VecDeque::new();  // BFS
+    blocks_to_analyze.push_back(block);
+
+    while !blocks_to_analyze.is_empty() {
+        let current = blocks_to_analyze.pop_front().unwrap();
+        // For all blocks that this block supports, check if they're supported by more than 1 block
+        for &touched in &supports[current] {
+            if supported_by[touched].iter().all(|&support| {
+                to_remove.contains(&support) // all supports of the supported block need to have been marked for removal
+            }) {
+                to_remove.insert(touched);  // this block will fall since all of it's supports will fall
+                blocks_to_analyze.push_back(touched);  // analyse the blocks this block supports
+            }
+        }
+    }
+
+    to_remove.len() as u32 - 1  // -1 because we don't count the block itself
+}
+
+// Lets the blocks, sorted by z, fall until they rest on the ground or on other blocks.
+// Returns the blocks each block supports and the blocks it's supported by.
+fn settle(blocks: &mut [Cuboid]) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let mut supported_by: Vec<Vec<usize>> = vec![Vec::new(); blocks.len()];
+    let mut supports: Vec<Vec<usize>> = vec![Vec::new(); blocks.len()];
+
+    // Index of the block filling each cube of space, if any
+    let top = blocks.iter().fold(ORIGIN_3D, |top, block| top.max(block.max));
+    let mut space = Grid3::new(top.x as usize + 1, top.y as usize + 1, top.z as usize + 1, None);
+
+    for i in 0..blocks.len() {
+        // Fall one level at a time until a block lies right below, or the ground at z = 0
+        while blocks[i].min.z > 1 {
+            let below = Cuboid {
+                min: blocks[i].min + BELOW,
+                max: Point3::new(blocks[i].max.x, blocks[i].max.y, blocks[i].min.z - 1),
+            };
+            let mut touched = below.points().filter_map(|cube| space[cube]).collect::<Vec<usize>>();
+            if !touched.is_empty() {
+                touched.sort_unstable();
+                touched.dedup();
+                for &o in &touched {
+                    supports[o].push(i);
+                }
+                supported_by[i] = touched;
+                break;
+            }
+            let height = blocks[i].min.z - 1;
+            set_height(&mut blocks[i], height);
+        }
+
+        space.fill(&blocks[i], Some(i));
+    }
+
+    (supports, supported_by)
+}
+
+fn solve(mut input: Vec<Cuboid>) -> (u32, u32) {
+    // Sort by z
+    input.sort_by_key(|block| block.min.z);
+    let (supports, supported_by) = settle(&mut input);
+
+    // Count number of removable blocks. A block can be removed if the blocks it touches are supported by more than 1 block
+    let removable_blocks = (0..input.len())
+        .filter(|i| {
+            // For all blocks this block supports, check if they're supported by more than 1 block
+            supports[*i]
+                .iter()
+                .all(|&touched| supported_by[touched].len() > 1)
+        })
+        .count() as u32;
+
+    let mut chain_size = Vec::new();  // chain size for each block if it was removed
+    for i in 0..input.len() {
+        chain_size.push(calculate_chain(i, &supports, &supported_by));
+    }
+
+    // Find the sum of blocks that would fall if each block was removed
+    let sum = chain_size.iter().sum();
+
+    (removable_blocks, sum)
+}
+
+fn parse_point(index: usize, line: &str, fragment: &str) -> Result<Point3, ParseError> {
+    let coordinates = fragment
+        .split(',')
+        .map(|s| parse_number::<i64>(index, line, s))
+        .collect::<Result<Vec<_>, _>>()?;
+    match coordinates[..] {
+        [x, y, z] => Ok(Point3::new(x, y, z)),
+        _ => Err(ParseError::at(index, line, fragment, "expected 3 coordinates")),
+    }
+}
+
+// Largest space the blocks may span, as settling them keeps a block id for each of its cubes. The puzzle needs
+// about 35 thousand.
+const MAX_CUBES: i64 = 1 << 22;
+
+fn parse(input: &[String]) -> Result<Vec<Cuboid>, ParseError> {
+    let mut blocks = Vec::new();
+    let mut top = ORIGIN_3D;
+    for (index, line) in input.iter().enumerate() {
+        let (start, end) = line
+            .split_once('~')
+            .ok_or_else(|| ParseError::at(index, line, line, "expected 'start~end'"))?;
+
+        let block = Cuboid {
+            min: parse_point(index, line, start)?,
+            max: parse_point(index, line, end)?,
+        };
+
+        if block.min.x > block.max.x || block.min.y > block.max.y || block.min.z > block.max.z {
+            return Err(ParseError::at(index, line, end, "end must not be before start"));
+        }
+        if block.min.x < 0 || block.min.y < 0 || block.min.z < 1 {
+            return Err(ParseError::at(index, line, start, "blocks must be above the ground, at z >= 1 and x, y >= 0"));
+        }
+        top = top.max(block.max);
+        let cubes = [top.x, top.y, top.z]
+            .into_iter()
+            .try_fold(1_i64, |cubes, max| max.checked_add(1).and_then(|size| cubes.checked_mul(size)));
+        if cubes.filter(|&cubes| cubes <= MAX_CUBES).is_none() {
+            let message = format!("the blocks must fit in {} cubes, from the origin", MAX_CUBES);
+            return Err(ParseError::at(index, line, end, message));
+        }
+
+        blocks.push(block);
+    }
+
+    if blocks.is_empty() {
+        return Err(ParseError::missing_line(0, "expected at least one block"));
+    }
+
+    Ok(blocks)
+}
+
+pub const EXAMPLE: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Cuboid>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        solve(input.to_vec()).0
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        solve(input.to_vec()).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day22>(EXAMPLE);
+        assert_eq!(Day22::part1(&input), 5);
+        assert_eq!(Day22::part2(&input), 7);
+    }
+
+    #[test]
+    fn test_settle() {
+        let mut blocks = parse_example::<Day22>(EXAMPLE);
+        let (supports, supported_by) = settle(&mut blocks);
+        // The last block falls onto the one below, which lands on the fifth layer
+        assert_eq!(blocks.iter().map(|block| block.min.z).collect::<Vec<_>>(), [1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(supports[0], [1, 2]);
+        assert_eq!(supported_by[3], [1, 2]);
+        assert_eq!(supported_by[0], Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_too_large() {
+        let lines = ["0,0,1~0,0,4", "0,0,5~0,0,4000000000"].map(String::from);
+        let error = Day22::parse(&lines).err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 7, "0,0,4000000000"));
+        let lines = ["0,0,1~9223372036854775807,0,1".to_string()];
+        assert_eq!(Day22::parse(&lines).err().unwrap().line, 1);
+    }
+}
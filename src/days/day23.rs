@@ -1,6 +1,7 @@
-use advent_of_code_2023::read_lines_as_vec;
-use advent_of_code_2023::util::grid::*;
-use advent_of_code_2023::util::point::*;
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::util::grid::*;
+use crate::util::point::*;
 
 use std::collections::HashMap;
 use std::collections::VecDeque;
@@ -10,7 +11,7 @@ use std::vec;
 // https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2023/day23.rs
 // And also inspiration for the grid and point implementations
 
-struct Puzzle {
+pub struct Puzzle {
     start_idx: usize,
     end_idx: usize,
     edges: Vec<u64>,
@@ -19,10 +20,46 @@ struct Puzzle {
     extra: u32,
 }
 
-fn parse(input: &[String]) -> Puzzle {
-    let mut grid = Grid::parse(input);
+fn parse(input: &[String]) -> Result<Puzzle, ParseError> {
+    for (index, line) in input.iter().enumerate() {
+        if let Some((i, c)) = line.char_indices().find(|&(_, c)| !".#^>v<".contains(c)) {
+            return Err(ParseError::at(index, line, &line[i..i + c.len_utf8()], "unknown tile"));
+        }
+    }
+
+    let mut grid = Grid::parse(input)?;
     let width = grid.width as i64;
     let height = grid.height as i64;
+    // Error on the tile at `point`
+    let error_at = |point: Point, message: &str| {
+        let (x, y) = (point.x as usize, point.y as usize);
+        let row = input[y].trim();
+        ParseError::at(y, &input[y], &row[x..x + 1], message)
+    };
+
+    // The start and end are moved one tile inside, where they must not meet
+    let (entrance, exit) = (Point::new(1, 0), Point::new(width - 2, height - 1));
+    if width < 3 {
+        return Err(ParseError::at(0, &input[0], input[0].trim(), "expected at least 3 columns"));
+    }
+    if height < 4 {
+        return Err(ParseError::missing_line(input.len(), "expected at least 4 rows"));
+    }
+    for (point, name) in [(entrance, "start"), (exit, "end")] {
+        if grid[point] != b'.' {
+            return Err(error_at(point, &format!("expected the {} as '.'", name)));
+        }
+    }
+    // The border is all forest except for the start and end, so that the paths never leave the grid
+    for y in 0..height {
+        for x in 0..width {
+            let point = Point::new(x, y);
+            let border = x == 0 || y == 0 || x == width - 1 || y == height - 1;
+            if border && grid[point] != b'#' && point != entrance && point != exit {
+                return Err(error_at(point, "expected forest '#' on the border"));
+            }
+        }
+    }
 
     // To make things easier we'll replace start and end points with # and add the cost later
     // This allows us to not check for any boundaries
@@ -47,14 +84,14 @@ fn parse(input: &[String]) -> Puzzle {
         // 0th row and 0th column are walls, as well as the last row and column
         for x in 1..width - 1 {
             let pos = Point::new(x, y);
-            if grid[pos] != b'#' {
-                let neighbors = ORTHOGONALS.iter().map(|&dir| pos + dir)
-                    .filter(|e| grid[*e] != b'#')
-                    .collect::<Vec<_>>();
-                if neighbors.len() > 2 {
-                    grid[pos] = b'P';
-                    poi.insert(pos, poi.len());
+            let open = !matches!(grid[pos], b'#' | b'P');
+            if open && grid.orthogonal_neighbors_where(pos, |&tile| tile != b'#').count() > 2 {
+                // Sets of points of interest are bitmasks of a u64
+                if poi.len() == 64 {
+                    return Err(error_at(pos, "expected at most 62 junctions"));
                 }
+                grid[pos] = b'P';
+                poi.insert(pos, poi.len());
             }
         }
     }
@@ -97,8 +134,8 @@ fn parse(input: &[String]) -> Puzzle {
                         queue.push_back((next, cost + 1, forward));
                         grid[next] = b'#';
                     },
-                    _ => {  // slope ^, >, <, v
-                        let slope_dir = Point::from(grid[next]);
+                    slope => {  // slope ^, >, <, v, the only tiles left after parsing
+                        let slope_dir = Point::from(Direction::try_from(slope).unwrap());
                         let same = dir == slope_dir;  // neighbour points forward
                         queue.push_back((next, cost+1, same && forward));  // once direction inverts it can never go forward again
                         grid[next] = b'#';
@@ -108,6 +145,12 @@ fn parse(input: &[String]) -> Puzzle {
         }
     }
 
+    for (index, point, name) in [(0, entrance, "start"), (1, exit, "end")] {
+        if undirected_edges[index] == 0 {
+            return Err(error_at(point, &format!("no path leads from the {} to a junction", name)));
+        }
+    }
+
     // Heuristics to make the algorithm faster
     // Since start and end nodes only connect to one other node, we can remove them from the graph by compressing the graph
     let start = undirected_edges[0].trailing_zeros() as usize;
@@ -133,15 +176,14 @@ fn parse(input: &[String]) -> Puzzle {
 
     let extra = 2 + weights[0][start] + weights[1][end];  // compensate for start and end points being moved and the graph compressed
 
-    Puzzle {
+    Ok(Puzzle {
         start_idx: start,
         end_idx: end,
         edges: directed_edges,
         undirected_edges,
         weights,
         extra,
-    }
-
+    })
 }
 
 fn part1(input: &Puzzle) -> u32 {
@@ -190,46 +232,84 @@ fn part2(input: &Puzzle) -> u32 {
     dfs(input, input.start_idx, 1 << input.start_idx, 0) + input.extra
 }
 
-fn main() {
-    let _lines = read_lines_as_vec("inputs/23.txt").unwrap();
-    let _example = r#"#.#####################
-    #.......#########...###
-    #######.#########.#.###
-    ###.....#.>.>.###.#.###
-    ###v#####.#v#.###.#.###
-    ###.>...#.#.#.....#...#
-    ###v###.#.#.#########.#
-    ###...#.#.#.......#...#
-    #####.#.#.#######.#.###
-    #.....#.#.#.......#...#
-    #.#####.#.#.#########v#
-    #.#...#...#...###...>.#
-    #.#.#v#######v###.###v#
-    #...#.>.#...>.>.#.###.#
-    #####v#.#.###v#.#.###.#
-    #.....#...#...#.#.#...#
-    #.#########.###.#.#.###
-    #...###...#...#...#.###
-    ###.###.#.###v#####v###
-    #...#...#.#.>.>.#.>.###
-    #.###.###.#.###.#.#v###
-    #.....###...###...#...#
-    #####################.#"#
-        .lines()
-        .map(|l| l.trim().to_string())
-        .collect::<Vec<String>>();
-
-    let now = std::time::Instant::now();
-    let map = parse(&_lines);
-    println!("Parsing took {}us", now.elapsed().as_micros());
-
-    let now = std::time::Instant::now();
-    let r1 = part1(&map);
-    println!("Part 1 took {}us", now.elapsed().as_micros());
-    println!("Part 1: {}", r1);
-
-    let now = std::time::Instant::now();
-    let r2 = part2(&map);
-    println!("Part 2 took {}ms", now.elapsed().as_millis());
-    println!("Part 2: {}", r2);
+pub const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Puzzle;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day23>(EXAMPLE);
+        assert_eq!(Day23::part1(&input), 94);
+        assert_eq!(Day23::part2(&input), 154);
+    }
+
+    #[test]
+    fn test_invalid_maps() {
+        let error_of = |map: &str| Day23::parse(&crate::input::split_lines(map)).err().unwrap();
+        assert_eq!(error_of("#.#\n#.#").message, "expected at least 4 rows");
+        let error = error_of("###\n#.#\n#.#\n#.#");
+        assert_eq!((error.line, error.column, error.message.as_str()), (1, 2, "expected the start as '.'"));
+        let error = error_of("#.#\n..#\n#.#\n#.#");
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = error_of("#.###\n#.#.#\n#####\n#.#.#\n###.#");
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 2, "."));
+
+        // 7 rows of 8 junctions and 2 rows of 7, plus the start and end
+        let mut rows = vec![format!("#.{}", "#".repeat(16))];
+        for _ in 0..9 {
+            rows.push(format!("#{}#", ".".repeat(16)));
+            rows.push(format!("#{}#", ".#".repeat(8)));
+        }
+        rows.pop();
+        rows.push(format!("{}.#", "#".repeat(16)));
+        assert_eq!(error_of(&rows.join("\n")).message, "expected at most 62 junctions");
+    }
 }
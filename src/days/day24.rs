@@ -1,40 +1,46 @@
-use advent_of_code_2023::read_lines_as_vec;
+use crate::error::{parse_number, ParseError};
+use crate::solution::Solution;
+use crate::util::point3::Point3;
 use nalgebra::{Matrix6, Vector6};
 
-#[derive(Debug, Clone, Copy)]
-struct Point {
-    x: i64,
-    y: i64,
-    z: i64,
+pub struct Line {
+    p0: Point3,
+    v: Point3,
 }
 
-impl Point {
-    fn new(x: i64, y: i64, z: i64) -> Self {
-        Self { x, y, z }
+fn parse_point(index: usize, line: &str, fragment: &str) -> Result<Point3, ParseError> {
+    let coordinates = fragment
+        .split(',')
+        .map(|s| parse_number::<i64>(index, line, s.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    match coordinates[..] {
+        [x, y, z] => Ok(Point3::new(x, y, z)),
+        _ => Err(ParseError::at(index, line, fragment.trim(), "expected 3 coordinates")),
     }
 }
 
-struct Line {
-    p0: Point,
-    v: Point,
-}
-
-fn parse(input: &[String]) -> Vec<Line> {
+fn parse(input: &[String]) -> Result<Vec<Line>, ParseError> {
     let mut lines = Vec::new();
 
-    for line in input {
-        let mut parts = line.trim().split('@');
-        let pos = parts.next().unwrap().trim().split(',').map(|s| s.trim().parse::<i64>().unwrap()).collect::<Vec<_>>();
-        let vel = parts.next().unwrap().trim().split(',').map(|s| s.trim().parse::<i64>().unwrap()).collect::<Vec<_>>();
+    for (index, line) in input.iter().enumerate() {
+        let (pos, vel) = line
+            .split_once('@')
+            .ok_or_else(|| ParseError::at(index, line, line, "expected 'position @ velocity'"))?;
         lines.push(Line {
-            p0: Point::new(pos[0], pos[1], pos[2]),
-            v: Point::new(vel[0], vel[1], vel[2]),
+            p0: parse_point(index, line, pos)?,
+            v: parse_point(index, line, vel)?,
         });
     }
-    lines
+
+    // Part 2 solves for the rock with the hailstones at indices 0, 1 and 4
+    if lines.len() < 5 {
+        return Err(ParseError::missing_line(input.len(), "expected at least 5 hailstones"));
+    }
+    Ok(lines)
 }
 
-fn part1(input: &[Line]) -> u32 {
+// Count the crossings of the paths of each pair of hailstones that happen inside the test area
+fn part1(input: &[Line], min: f64, max: f64) -> u32 {
     let mut crossing_lines = 0;
     for (i, line_a) in input.iter().enumerate() {
         let x0_1 = line_a.p0.x as f64;
@@ -73,9 +79,7 @@ fn part1(input: &[Line]) -> u32 {
                 continue;
             }
 
-            const MIN: f64 = 200000000000000.0;
-            const MAX: f64 = 400000000000000.0;
-            if (MIN..=MAX).contains(&x) && (MIN..=MAX).contains(&y) {
+            if (min..=max).contains(&x) && (min..=max).contains(&y) {
                 crossing_lines += 1;
             }
         }
@@ -153,25 +157,52 @@ fn part2(input: &[Line]) -> u64 {
     (x[(0, 0)] + x[(1, 0)] + x[(2, 0)]) as u64
 }
 
-fn main() {
-    let _lines = read_lines_as_vec("inputs/24.txt").unwrap();
-    let _example = r#"19, 13, 30 @ -2,  1, -2
-    18, 19, 22 @ -1, -1, -2
-    20, 25, 34 @ -2, -2, -4
-    12, 31, 28 @ -1, -2, -1
-    20, 19, 15 @  1, -5, -3"#.lines().map(|l| l.trim().to_string()).collect::<Vec<String>>();
-
-    let now = std::time::Instant::now();
-    let hazel_lines = parse(&_lines);
-    println!("Parsing took {}us", now.elapsed().as_micros());
-
-    let now = std::time::Instant::now();
-    let r1 = part1(&hazel_lines);
-    println!("Part 1 took {}us", now.elapsed().as_micros());
-    println!("Part 1: {}", r1);
-
-    let now = std::time::Instant::now();
-    let r2 = part2(&hazel_lines);
-    println!("Part 2 took {}us", now.elapsed().as_micros());
-    println!("Part 2: {}", r2);
+// Test area of the example goes from 7 to 27
+pub const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Line>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input, 200000000000000.0, 400000000000000.0)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day24>(EXAMPLE);
+        assert_eq!(part1(&input, 7.0, 27.0), 2);
+        assert_eq!(Day24::part2(&input), 47);
+    }
+
+    #[test]
+    fn test_too_few_hailstones() {
+        let lines = crate::input::split_lines(EXAMPLE);
+        let error = Day24::parse(&lines[..4]).err().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (5, "expected at least 5 hailstones"));
+    }
 }
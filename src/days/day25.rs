@@ -1,23 +1,27 @@
-use advent_of_code_2023::read_lines_as_vec;
+use crate::error::ParseError;
+use crate::solution::Solution;
 use std::collections::HashMap;
 use nalgebra::DMatrix;
 
-struct Input {
+pub struct Input {
     adjacency_matrix: DMatrix<f32>,
     degree_matrix: DMatrix<f32>,
 }
 
-fn parse(input: &[String]) -> Input {
+fn parse(input: &[String]) -> Result<Input, ParseError> {
     // Since we don't know the number of nodes beforehand, let's use a map to store the node along with an index
     let mut nodes = HashMap::new();
 
-    for line in input {
-        let mut parts = line.trim().split(':');
-        let from = parts.next().unwrap();
-        let to = parts
-            .next().unwrap().trim()
-            .split(' ')
-            .map(|s| s.trim()).collect::<Vec<_>>();
+    for (index, line) in input.iter().enumerate() {
+        let (from, to) = line
+            .trim()
+            .split_once(':')
+            .filter(|(from, _)| !from.is_empty())
+            .ok_or_else(|| ParseError::at(index, line, line, "expected 'component: connections'"))?;
+        let to = to.split_whitespace().collect::<Vec<_>>();
+        if to.is_empty() {
+            return Err(ParseError::end_of_line(index, line, "expected connected components"));
+        }
 
         // Make sure all nodes exist in the map
         let to_indices = to
@@ -49,10 +53,10 @@ fn parse(input: &[String]) -> Input {
         }
     }
 
-    Input {
+    Ok(Input {
         adjacency_matrix: adj_matrix,
         degree_matrix: deg_matrix,
-    }
+    })
 }
 
 fn part_1(input: &Input) -> u32 {
@@ -91,34 +95,56 @@ fn part_1(input: &Input) -> u32 {
             negative += 1;
         }
     });
-    println!("Positive: {}, Negative: {}", positive, negative);
     positive * negative
 }
 
-fn main() {
-    let _lines = read_lines_as_vec("inputs/25.txt").unwrap();
-    let _example = r#"jqt: rhn xhk nvd
-    rsh: frs pzl lsr
-    xhk: hfx
-    cmg: qnr nvd lhk bvb
-    rhn: xhk bvb hfx
-    bvb: xhk hfx
-    pzl: lsr hfx nvd
-    qnr: nvd
-    ntq: jqt hfx bvb xhk
-    nvd: lhk
-    lsr: lhk
-    rzs: qnr cmg lsr rsh
-    frs: qnr lhk lsr"#
-        .lines()
-        .map(|l| l.trim().to_string())
-        .collect::<Vec<_>>();
-
-    let now = std::time::Instant::now();
-    let input = parse(&_lines);
-    println!("Parsing took {}ms", now.elapsed().as_millis());
-
-    let now = std::time::Instant::now();
-    let result = part_1(&input);
-    println!("Part 1: {} ({}ms)", result, now.elapsed().as_millis());
+pub const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = &'static str;
+
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[("example", EXAMPLE)];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError> {
+        parse(lines)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part_1(input)
+    }
+
+    // There is no puzzle for part 2 on the last day
+    fn part2(_: &Self::Input) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::parse_example;
+
+    #[test]
+    fn test_example() {
+        let input = parse_example::<Day25>(EXAMPLE);
+        assert_eq!(Day25::part1(&input), 54);
+        assert_eq!(Day25::part2(&input), "Merry Christmas!");
+    }
 }
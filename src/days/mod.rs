@@ -0,0 +1,117 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use std::path::PathBuf;
+
+use crate::bench::{measure, Bench, Timings};
+use crate::error::ParseError;
+use crate::solution::{run, Answer, Part, Runner, Solution};
+
+pub struct Day {
+    pub number: u8,
+    pub run: Runner,
+    pub bench: Bench,
+    pub examples: &'static [(&'static str, &'static str)],
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8) -> Self {
+        Day {
+            number,
+            run: run::<S>,
+            bench: measure::<S>,
+            examples: S::EXAMPLES,
+        }
+    }
+
+    /// Solves the requested part, or both parts if `part` is `None`
+    pub fn solve(&self, lines: &[String], part: Option<Part>) -> Result<Vec<Answer>, ParseError> {
+        (self.run)(lines, part).map_err(|error| error.in_day(self.number))
+    }
+
+    /// Times each phase over `iterations` runs, see `solve`
+    pub fn measure(&self, lines: &[String], part: Option<Part>, iterations: usize) -> Result<Timings, ParseError> {
+        (self.bench)(lines, part, iterations).map_err(|error| error.in_day(self.number))
+    }
+
+    /// Default location of the puzzle input for this day
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(format!("inputs/{}.txt", self.number))
+    }
+}
+
+// Every day is registered here, in order
+pub const DAYS: [Day; 25] = [
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13),
+    Day::new::<day14::Day14>(14),
+    Day::new::<day15::Day15>(15),
+    Day::new::<day16::Day16>(16),
+    Day::new::<day17::Day17>(17),
+    Day::new::<day18::Day18>(18),
+    Day::new::<day19::Day19>(19),
+    Day::new::<day20::Day20>(20),
+    Day::new::<day21::Day21>(21),
+    Day::new::<day22::Day22>(22),
+    Day::new::<day23::Day23>(23),
+    Day::new::<day24::Day24>(24),
+    Day::new::<day25::Day25>(25),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::split_lines;
+    use std::panic::catch_unwind;
+
+    // Both parts run on every example, including the examples written for the other part
+    #[test]
+    fn test_examples_run() {
+        for day in &DAYS {
+            for (name, example) in day.examples {
+                let answers = catch_unwind(|| day.solve(&split_lines(example), None));
+                let answers = answers.unwrap_or_else(|_| panic!("Day {} {} panicked", day.number, name));
+                let answers = answers.unwrap_or_else(|error| panic!("Day {} {}: {}", day.number, name, error));
+                assert_eq!(answers.len(), 2, "Day {} {}", day.number, name);
+            }
+        }
+    }
+}
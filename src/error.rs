@@ -0,0 +1,127 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    // Both line and column start at 1
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error on the line at `line_index` (starting at 0), where `fragment` is the offending slice of `line`.
+    /// If `fragment` does not lie entirely within `line` the error points at the start of the line.
+    pub fn at(line_index: usize, line: &str, fragment: &str, message: impl Into<String>) -> Self {
+        // Fragments before the line wrap around to a huge offset, so both checks also reject them
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let inside = offset <= line.len() && fragment.len() <= line.len() - offset;
+        let column = if inside { offset + 1 } else { 1 };
+
+        ParseError {
+            day: None,
+            line: line_index + 1,
+            column,
+            text: fragment.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error on a line that is missing something at its end
+    pub fn end_of_line(line_index: usize, line: &str, message: impl Into<String>) -> Self {
+        ParseError::at(line_index, line, &line[line.len()..], message)
+    }
+
+    /// Error on a line that is missing altogether, such as in truncated inputs
+    pub fn missing_line(line_index: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line: line_index + 1,
+            column: 1,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `fragment` of `line` as a number, or any other type implementing `FromStr`
+pub fn parse_number<T: FromStr>(line_index: usize, line: &str, fragment: &str) -> Result<T, ParseError> {
+    fragment
+        .parse::<T>()
+        .map_err(|_| ParseError::at(line_index, line, fragment, "invalid number"))
+}
+
+/// Gets the line at `line_index`, failing if the input is too short
+pub fn get_line<'a>(lines: &'a [String], line_index: usize, expected: &str) -> Result<&'a str, ParseError> {
+    lines
+        .get(line_index)
+        .map(String::as_str)
+        .ok_or_else(|| ParseError::missing_line(line_index, format!("expected {}", expected)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of_fragment() {
+        let line = "R 6 (#70c710)";
+        let error = ParseError::at(2, line, &line[2..3], "invalid number");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "6");
+
+        let error = ParseError::end_of_line(0, line, "expected color");
+        assert_eq!(error.column, line.len() + 1);
+
+        // Not a slice of the line
+        let error = ParseError::at(0, line, "R", "invalid direction");
+        assert_eq!(error.column, 1);
+
+        // Right after the line in memory, as the optimizer may place a literal
+        let text = "R 6 (#70c710)R";
+        let (line, after) = text.split_at(13);
+        assert_eq!(ParseError::at(0, line, after, "invalid direction").column, 1);
+        assert_eq!(ParseError::at(0, line, &text[12..], "invalid direction").column, 1);
+        assert_eq!(ParseError::at(0, line, &line[12..], "invalid direction").column, 13);
+    }
+
+    #[test]
+    fn test_display() {
+        let line = "%a -> b";
+        let error = ParseError::at(4, line, &line[0..1], "unknown module type").in_day(20);
+        assert_eq!(error.to_string(), "day 20, line 5, column 1: unknown module type \"%\"");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let line = "seeds: 79 x4";
+        assert_eq!(parse_number::<u64>(0, line, &line[7..9]), Ok(79));
+        let error = parse_number::<u64>(0, line, &line[10..12]).unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (11, "x4"));
+    }
+}
@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::days::Day;
+
+/// Where the puzzle input of a day is read from
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Example(String),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io { origin: String, error: io::Error },
+    Utf8 { origin: String, line: usize },
+    UnknownExample { day: u8, name: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io { origin, error } => write!(f, "Could not read {}: {}", origin, error),
+            InputError::Utf8 { origin, line } => write!(f, "{}:{}: invalid UTF-8", origin, line),
+            InputError::UnknownExample { day, name } => write!(f, "Day {} has no example named '{}'", day, name),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Source {
+    pub fn load(&self, day: &Day) -> Result<Vec<String>, InputError> {
+        match self {
+            Source::File(path) => read_file(path),
+            Source::Stdin => read_stdin(),
+            Source::Example(name) => day
+                .examples
+                .iter()
+                .find(|(example, _)| example == name)
+                .map(|(_, input)| split_lines(input))
+                .ok_or(InputError::UnknownExample {
+                    day: day.number,
+                    name: name.clone(),
+                }),
+        }
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<Vec<String>, InputError> {
+    let origin = path.display().to_string();
+    match std::fs::read(path) {
+        Ok(bytes) => decode(&origin, &bytes),
+        Err(error) => Err(InputError::Io { origin, error }),
+    }
+}
+
+pub fn read_stdin() -> Result<Vec<String>, InputError> {
+    let origin = "<stdin>".to_string();
+    let mut bytes = Vec::new();
+    match io::stdin().read_to_end(&mut bytes) {
+        Ok(_) => decode(&origin, &bytes),
+        Err(error) => Err(InputError::Io { origin, error }),
+    }
+}
+
+/// Splits an in-memory input into lines, with the same trimming as inputs read from files
+pub fn split_lines(input: &str) -> Vec<String> {
+    normalize(input.lines().map(str::to_owned).collect())
+}
+
+// Decodes each line separately so that errors can point at the offending line
+fn decode(origin: &str, bytes: &[u8]) -> Result<Vec<String>, InputError> {
+    let mut lines = Vec::new();
+    for (index, line) in bytes.split(|&b| b == b'\n').enumerate() {
+        let line = String::from_utf8(line.to_vec()).map_err(|_| InputError::Utf8 {
+            origin: origin.to_owned(),
+            line: index + 1,
+        })?;
+        lines.push(line);
+    }
+
+    Ok(normalize(lines))
+}
+
+// Trailing whitespace is removed from every line (including \r of CRLF endings), as are trailing empty lines
+fn normalize(mut lines: Vec<String>) -> Vec<String> {
+    for line in lines.iter_mut() {
+        line.truncate(line.trim_end().len());
+    }
+    while lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_lines() {
+        assert_eq!(split_lines("a b  \r\nc\t\n\n\n"), vec!["a b", "c"]);
+        assert_eq!(split_lines("a\n\nb"), vec!["a", "", "b"]);
+        assert!(split_lines("").is_empty());
+    }
+
+    #[test]
+    fn test_decode_reports_line() {
+        let error = decode("input.txt", b"fine\nalso fine\n\xff\xfe\n").unwrap_err();
+        assert_eq!(error.to_string(), "input.txt:3: invalid UTF-8");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = read_file(Path::new("inputs/does_not_exist.txt")).unwrap_err();
+        assert!(error.to_string().starts_with("Could not read inputs/does_not_exist.txt"));
+    }
+}
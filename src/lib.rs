@@ -1,17 +1,23 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod input;
+pub mod output;
+pub mod solution;
+
 pub mod util {
+    pub mod bit_grid;
+    pub mod cycle;
+    pub mod flood;
     pub mod grid;
+    pub mod grid3;
+    pub mod image;
+    pub mod interval;
+    pub mod math;
     pub mod point;
-}
-
-use std::fs::File;
-use std::io::{self, BufRead};
-
-pub fn read_lines(filepath: &str) -> io::Result<io::Lines<io::BufReader<File>>> {
-    let file = File::open(filepath)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-pub fn read_lines_as_vec(filepath: &str) -> io::Result<Vec<String>> {
-    let lines = read_lines(filepath)?;
-    Ok(lines.flatten().collect())
+    pub mod point3;
+    pub mod render;
+    pub mod search;
+    pub mod sparse_grid;
 }
@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+/// Output format of the `aoc` commands, `Text` is meant for humans and the others for scripts
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("Unknown format: {}", s)),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Value {
+    Number(u128),
+    Text(String),
+}
+
+/// Named fields of one result, all records of an output share the same field names
+pub type Record = Vec<(&'static str, Value)>;
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+// Fields are quoted only when they contain the separator, a quote or a line break
+fn separated_field(text: &str, separator: char) -> String {
+    if text.contains([separator, '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+// Text of a field in a text table, without quoting
+fn text_field(value: &Value) -> String {
+    match value {
+        Value::Number(n) => n.to_string(),
+        Value::Text(text) => text.clone(),
+    }
+}
+
+/// Formats the records as JSON lines (one object per line), as CSV/TSV with a header row, or as a text table
+/// with a header row and aligned columns. The commands print their own text output, the table is a generic
+/// fallback.
+pub fn format_records(format: Format, records: &[Record]) -> String {
+    let mut output = String::new();
+
+    match format {
+        Format::Text => {
+            let Some(first) = records.first() else {
+                return output;
+            };
+
+            let header = first.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+            let rows = records
+                .iter()
+                .map(|record| record.iter().map(|(_, value)| text_field(value)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let widths = (0..header.len())
+                .map(|i| rows.iter().chain([&header]).map(|row| row[i].chars().count()).max().unwrap())
+                .collect::<Vec<_>>();
+            for row in [&header].into_iter().chain(&rows) {
+                let fields = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(field, &width)| format!("{:<width$}", field, width = width))
+                    .collect::<Vec<_>>();
+                output.push_str(fields.join("  ").trim_end());
+                output.push('\n');
+            }
+        }
+        Format::Json => {
+            for record in records {
+                let fields = record
+                    .iter()
+                    .map(|(name, value)| match value {
+                        Value::Number(n) => format!("{}: {}", json_string(name), n),
+                        Value::Text(text) => format!("{}: {}", json_string(name), json_string(text)),
+                    })
+                    .collect::<Vec<_>>();
+                output.push_str(&format!("{{{}}}\n", fields.join(", ")));
+            }
+        }
+        Format::Csv | Format::Tsv => {
+            let separator = if format == Format::Csv { ',' } else { '\t' };
+            let Some(first) = records.first() else {
+                return output;
+            };
+
+            let header = first.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+            output.push_str(&header.join(&separator.to_string()));
+            output.push('\n');
+            for record in records {
+                let fields = record
+                    .iter()
+                    .map(|(_, value)| match value {
+                        Value::Number(n) => n.to_string(),
+                        Value::Text(text) => separated_field(text, separator),
+                    })
+                    .collect::<Vec<_>>();
+                output.push_str(&fields.join(&separator.to_string()));
+                output.push('\n');
+            }
+        }
+    }
+
+    output
+}
+
+/// 64-bit FNV-1a hash
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Hash of an input as loaded, identifying which input produced a result
+pub fn input_hash(lines: &[String]) -> String {
+    format!("{:016x}", fnv1a(lines.join("\n").as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            vec![("day", Value::Number(1)), ("answer", Value::Text("53651".to_string()))],
+            vec![("day", Value::Number(25)), ("answer", Value::Text("Merry, \"Christmas\"!".to_string()))],
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            format_records(Format::Json, &records()),
+            "{\"day\": 1, \"answer\": \"53651\"}\n{\"day\": 25, \"answer\": \"Merry, \\\"Christmas\\\"!\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_separated() {
+        assert_eq!(
+            format_records(Format::Csv, &records()),
+            "day,answer\n1,53651\n25,\"Merry, \"\"Christmas\"\"!\"\n"
+        );
+        assert_eq!(
+            format_records(Format::Tsv, &records()),
+            "day\tanswer\n1\t53651\n25\t\"Merry, \"\"Christmas\"\"!\"\n"
+        );
+        assert_eq!(format_records(Format::Tsv, &[]), "");
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            format_records(Format::Text, &records()),
+            "day  answer\n1    53651\n25   Merry, \"Christmas\"!\n"
+        );
+        assert_eq!(format_records(Format::Text, &[]), "");
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+        assert_eq!(input_hash(&["a".to_string()]), "af63dc4c8601ec8c");
+    }
+}
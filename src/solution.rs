@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+
+/// Value returned by a part. A part returns a `Result` when some inputs have no answer, such as the examples
+/// written for the other part, with the reason as error.
+pub trait PartAnswer {
+    fn to_answer(&self) -> Result<String, String>;
+}
+
+// Answers that are always there
+macro_rules! impl_part_answer {
+    ($($answer:ty),*) => {
+        $(impl PartAnswer for $answer {
+            fn to_answer(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_part_answer!(u32, u64, usize, i64, &'static str, String);
+
+impl<T: PartAnswer> PartAnswer for Result<T, String> {
+    fn to_answer(&self) -> Result<String, String> {
+        self.as_ref().map_err(String::clone).and_then(T::to_answer)
+    }
+}
+
+/// A day's puzzle: the input is parsed once and shared by both parts
+pub trait Solution {
+    type Input;
+    type Answer1: PartAnswer;
+    type Answer2: PartAnswer;
+
+    /// Example inputs from the puzzle description, by name
+    const EXAMPLES: &'static [(&'static str, &'static str)] = &[];
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {}", value)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    // The answer, or why the input has none
+    pub value: Result<String, String>,
+    // Time taken by the part, excluding parsing
+    pub elapsed: Duration,
+}
+
+/// Type-erased entry point of a day, so that all days can live in the same registry
+pub type Runner = fn(&[String], Option<Part>) -> Result<Vec<Answer>, ParseError>;
+
+/// Parses the input and solves the requested part, or both parts if `part` is `None`
+pub fn run<S: Solution>(lines: &[String], part: Option<Part>) -> Result<Vec<Answer>, ParseError> {
+    let input = S::parse(lines)?;
+    let mut answers = Vec::new();
+
+    if part != Some(Part::Two) {
+        let start = Instant::now();
+        let value = S::part1(&input).to_answer();
+        answers.push(Answer {
+            part: Part::One,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+    if part != Some(Part::One) {
+        let start = Instant::now();
+        let value = S::part2(&input).to_answer();
+        answers.push(Answer {
+            part: Part::Two,
+            value,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(answers)
+}
+
+/// Parses one of the examples of a day, for tests
+#[cfg(test)]
+pub(crate) fn parse_example<S: Solution>(example: &str) -> S::Input {
+    match S::parse(&crate::input::split_lines(example)) {
+        Ok(input) => input,
+        Err(error) => panic!("Invalid example: {}", error),
+    }
+}
@@ -0,0 +1,216 @@
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+use std::fmt::Display;
+
+/// Grid of booleans packed 64 to a word. Each row starts at a new word and the bits past the width are always
+/// zero, so that equal grids have equal words and hash the same.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Grid with every cell unset
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(64);
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+
+    /// Grid with the cells of `grid` that match `predicate` set
+    pub fn from_grid<T>(grid: &Grid<T>, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = BitGrid::new(grid.width, grid.height);
+        for y in 0..grid.height as i64 {
+            for x in 0..grid.width as i64 {
+                let point = Point::new(x, y);
+                if predicate(&grid[point]) {
+                    bits.insert(point);
+                }
+            }
+        }
+        bits
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.x < self.width as i64 && point.y >= 0 && point.y < self.height as i64
+    }
+
+    // Word holding the cell and the mask of the cell in it
+    #[inline]
+    fn position(&self, point: Point) -> (usize, u64) {
+        debug_assert!(self.contains(point));
+        let x = point.x as usize;
+        (point.y as usize * self.words_per_row + x / 64, 1 << (x % 64))
+    }
+
+    /// Whether the cell is set, cells outside the grid never are
+    #[inline]
+    pub fn get(&self, point: Point) -> bool {
+        if !self.contains(point) {
+            return false;
+        }
+        let (word, mask) = self.position(point);
+        self.words[word] & mask != 0
+    }
+
+    #[inline]
+    pub fn set(&mut self, point: Point, value: bool) {
+        let (word, mask) = self.position(point);
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    /// Sets the cell, returning whether it was unset before like `HashSet::insert`
+    #[inline]
+    pub fn insert(&mut self, point: Point) -> bool {
+        let (word, mask) = self.position(point);
+        let unset = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        unset
+    }
+
+    /// Number of cells that are set
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Sets every cell that is set in `other`, both grids must have the same size
+    pub fn union_with(&mut self, other: &BitGrid) {
+        assert_eq!((self.width, self.height), (other.width, other.height));
+        self.words.iter_mut().zip(&other.words).for_each(|(word, other)| *word |= other);
+    }
+
+    /// Unsets every cell that is not set in `other`, both grids must have the same size
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        assert_eq!((self.width, self.height), (other.width, other.height));
+        self.words.iter_mut().zip(&other.words).for_each(|(word, other)| *word &= other);
+    }
+
+    /// Copy with every cell moved by `offset`. Cells moved out of the grid are dropped and the cells left
+    /// behind are unset, so `shifted(RIGHT)` holds the cells whose left neighbor is set.
+    pub fn shifted(&self, offset: Point) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        let n = self.words_per_row;
+        if n == 0 {
+            return shifted;
+        }
+
+        let (q, r) = (offset.x.div_euclid(64), offset.x.rem_euclid(64) as u32);
+        let padding = match self.width % 64 {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        };
+        for y in 0..self.height {
+            let source = y as i64 - offset.y;
+            if source < 0 || source >= self.height as i64 {
+                continue;
+            }
+
+            let source = &self.words[source as usize * n..(source as usize + 1) * n];
+            let word = |i: i64| if (0..n as i64).contains(&i) { source[i as usize] } else { 0 };
+            let row = &mut shifted.words[y * n..(y + 1) * n];
+            // Bit x of the row is bit x - offset.x of the source row, which spans two words
+            for (i, target) in row.iter_mut().enumerate() {
+                let i = i as i64;
+                let carry = if r == 0 { 0 } else { word(i - q - 1) >> (64 - r) };
+                *target = word(i - q) << r | carry;
+            }
+            row[n - 1] &= padding;
+        }
+
+        shifted
+    }
+
+    /// Points of the cells that are set, in reading order
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (y, first) = (i / self.words_per_row, i % self.words_per_row * 64);
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| Point::new((first + bit) as i64, y as i64))
+        })
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height as i64 {
+            for x in 0..self.width as i64 {
+                write!(f, "{}", if self.get(Point::new(x, y)) { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::point::*;
+
+    fn bits(map: &str) -> BitGrid {
+        BitGrid::from_grid(&Grid::parse_str(map).unwrap(), |&c| c == b'#')
+    }
+
+    #[test]
+    fn test_cells() {
+        let mut grid = BitGrid::new(70, 2);
+        assert!(grid.insert(Point::new(66, 1)));
+        assert!(!grid.insert(Point::new(66, 1)));
+        grid.set(Point::new(3, 0), true);
+        assert!(grid.get(Point::new(66, 1)) && grid.get(Point::new(3, 0)));
+        assert!(!grid.get(Point::new(3, 1)) && !grid.get(Point::new(70, 0)) && !grid.get(Point::new(-1, 0)));
+        assert_eq!(grid.count_ones(), 2);
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [Point::new(3, 0), Point::new(66, 1)]);
+
+        grid.set(Point::new(3, 0), false);
+        assert_eq!(grid.count_ones(), 1);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut a = bits("##.\n...");
+        let b = bits(".##\n..#");
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union, bits("###\n..#"));
+        a.intersect_with(&b);
+        assert_eq!(a, bits(".#.\n..."));
+    }
+
+    #[test]
+    fn test_shifted() {
+        let grid = bits("#..#\n.##.\n...#");
+        assert_eq!(grid.shifted(RIGHT).to_string(), ".#..\n..##\n....\n");
+        assert_eq!(grid.shifted(LEFT).to_string(), "..#.\n##..\n..#.\n");
+        assert_eq!(grid.shifted(UP).to_string(), ".##.\n...#\n....\n");
+        assert_eq!(grid.shifted(DOWN).to_string(), "....\n#..#\n.##.\n");
+        assert_eq!(grid.shifted(Point::new(-2, 1)).to_string(), "....\n.#..\n#...\n");
+        assert_eq!(grid.shifted(Point::new(5, 0)).count_ones(), 0);
+
+        // Across words, and the bits past the width are dropped
+        let mut wide = BitGrid::new(130, 1);
+        for x in [0, 63, 64, 129] {
+            wide.insert(Point::new(x, 0));
+        }
+        assert_eq!(wide.shifted(RIGHT).iter().map(|p| p.x).collect::<Vec<_>>(), [1, 64, 65]);
+        assert_eq!(wide.shifted(Point::new(-64, 0)).iter().map(|p| p.x).collect::<Vec<_>>(), [0, 65]);
+        assert_eq!(wide.shifted(Point::new(66, 0)).iter().map(|p| p.x).collect::<Vec<_>>(), [66, 129]);
+        assert_eq!(wide.shifted(RIGHT).shifted(LEFT), {
+            wide.set(Point::new(129, 0), false);
+            wide
+        });
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::hash_map::{DefaultHasher, Entry};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// The functions below simulate a sequence of states `start`, `step(start)`, `step(step(start))`, ... which must
+// eventually repeat, otherwise they never return.
+
+/// Shape of an eventually periodic sequence of states: the states from index `start` on repeat every `length`
+/// steps
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Index of the first state equal to the state after `n` steps, always less than `start + length`
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// State after `n` steps, simulating no more than `start + length` of them
+    pub fn state_after<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.index(n)).fold(start.clone(), |state, _| step(&state))
+    }
+}
+
+// Stops at the first state whose key was already seen, keeping the keys of all the states before it
+fn find_cycle_by<S, K: Eq + Hash>(start: &S, mut step: impl FnMut(&S) -> S, mut key: impl FnMut(&S) -> K) -> Cycle {
+    let mut indices = HashMap::from([(key(start), 0)]);
+    let mut state = step(start);
+    loop {
+        let index = indices.len();
+        match indices.entry(key(&state)) {
+            Entry::Occupied(entry) => return Cycle { start: *entry.get(), length: index - entry.get() },
+            Entry::Vacant(entry) => entry.insert(index),
+        };
+        state = step(&state);
+    }
+}
+
+/// Cycle found by remembering every state, which takes a single step per state
+pub fn find_cycle<S: Clone + Eq + Hash>(start: &S, step: impl FnMut(&S) -> S) -> Cycle {
+    find_cycle_by(start, step, S::clone)
+}
+
+/// Like `find_cycle`, remembering only a 64-bit hash of each state. A collision between two different states
+/// would give a wrong cycle, which is unlikely enough to ignore for the few states of a puzzle.
+pub fn find_cycle_hashed<S: Hash>(start: &S, step: impl FnMut(&S) -> S) -> Cycle {
+    find_cycle_by(start, step, |state| {
+        let mut hasher = DefaultHasher::new();
+        state.hash(&mut hasher);
+        hasher.finish()
+    })
+}
+
+/// Floyd's tortoise and hare, which keeps only two states but takes about three steps per state
+pub fn floyd<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The hare goes twice as fast, they meet once both are in the cycle
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    // The distance between them is now a multiple of the length, so they meet again at the start of the cycle
+    let mut cycle_start = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start: cycle_start, length }
+}
+
+/// Brent's algorithm, which keeps only two states like `floyd` but takes fewer steps
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The tortoise teleports to the hare every power of two steps, until the hare comes back to it
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, they meet at the start of the cycle
+    let mut cycle_start = 0;
+    tortoise = start.clone();
+    hare = (0..length).fold(start.clone(), |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle { start: cycle_start, length }
+}
+
+/// State after `n` steps, such as a billion. Keeps one copy of each state until the first repetition, then simulates
+/// again up to the state equal to the one after `n` steps.
+pub fn state_after<S: Clone + Eq + Hash>(start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    find_cycle(start, &mut step).state_after(start, step, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3, ...
+    fn step(&n: &u32) -> u32 {
+        if n < 6 { n + 1 } else { 3 }
+    }
+
+    // A longer sequence that isn't a simple count
+    fn square(&n: &u64) -> u64 {
+        (n * n + 1) % 1009
+    }
+
+    fn simulate<S: Clone>(start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..n).fold(start.clone(), |state, _| step(&state))
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let expected = Cycle { start: 3, length: 4 };
+        assert_eq!(find_cycle(&0, step), expected);
+        assert_eq!(find_cycle_hashed(&0, step), expected);
+        assert_eq!(floyd(&0, step), expected);
+        assert_eq!(brent(&0, step), expected);
+
+        // Starting in the cycle
+        assert_eq!(brent(&4, step), Cycle { start: 0, length: 4 });
+        assert_eq!(floyd(&3, |&n| n), Cycle { start: 0, length: 1 });
+
+        let cycle = find_cycle(&12, square);
+        assert_eq!(cycle, Cycle { start: 39, length: 49 });
+        assert_eq!(find_cycle_hashed(&12, square), cycle);
+        assert_eq!(floyd(&12, square), cycle);
+        assert_eq!(brent(&12, square), cycle);
+        assert_eq!(simulate(&12, square, 39), simulate(&12, square, 39 + 49));
+        assert_ne!(simulate(&12, square, 38), simulate(&12, square, 38 + 49));
+    }
+
+    #[test]
+    fn test_state_after() {
+        let cycle = Cycle { start: 3, length: 4 };
+        assert_eq!([0, 2, 3, 6, 7, 8, 1_000_000_000].map(|n| cycle.index(n)), [0, 2, 3, 6, 3, 4, 4]);
+        assert_eq!(cycle.state_after(&0, step, 1_000_000_000), 4);
+
+        for n in [0, 1, 5, 50, 1000] {
+            assert_eq!(state_after(&12, square, n), simulate(&12, square, n), "after {} steps", n);
+        }
+        assert_eq!(state_after(&0, step, 1_000_000_000), 4);
+    }
+}
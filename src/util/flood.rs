@@ -0,0 +1,127 @@
+use crate::util::bit_grid::BitGrid;
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+
+impl<T> Grid<T> {
+    // Visits the cells matching `passable` reachable from the cells on `stack`, which must already be visited.
+    // `visit` marks a cell and returns whether it was not visited before.
+    fn fill(&self, mut stack: Vec<Point>, passable: &mut impl FnMut(&T) -> bool, mut visit: impl FnMut(Point) -> bool) {
+        while let Some(point) = stack.pop() {
+            for (neighbor, _) in self.orthogonal_neighbors_where(point, &mut *passable) {
+                if visit(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    /// Cells reachable from `start` by orthogonal steps through cells matching `passable`, none if `start` itself
+    /// doesn't match
+    pub fn flood_fill(&self, start: Point, mut passable: impl FnMut(&T) -> bool) -> BitGrid {
+        let mut filled = BitGrid::new(self.width, self.height);
+        if self.get(start).is_some_and(&mut passable) {
+            filled.insert(start);
+            self.fill(vec![start], &mut passable, |point| filled.insert(point));
+        }
+        filled
+    }
+
+    /// Labels the connected regions of cells matching `passable` from 0, in the reading order of their first cell.
+    /// Returns the label of each cell, `None` for cells that don't match, and the number of cells of each region.
+    pub fn components(&self, mut passable: impl FnMut(&T) -> bool) -> (Grid<Option<usize>>, Vec<usize>) {
+        let mut labels = Grid::new(self.width, self.height, None);
+        let mut sizes = Vec::new();
+
+        for y in 0..self.height as i64 {
+            for x in 0..self.width as i64 {
+                let start = Point::new(x, y);
+                if labels[start].is_some() || !passable(&self[start]) {
+                    continue;
+                }
+
+                let label = sizes.len();
+                let mut size = 1;
+                labels[start] = Some(label);
+                self.fill(vec![start], &mut passable, |point| {
+                    let new = labels[point].is_none();
+                    if new {
+                        labels[point] = Some(label);
+                        size += 1;
+                    }
+                    new
+                });
+                sizes.push(size);
+            }
+        }
+
+        (labels, sizes)
+    }
+
+    /// Cells matching `passable` that can't be reached from the border of the grid through such cells
+    pub fn enclosed(&self, mut passable: impl FnMut(&T) -> bool) -> BitGrid {
+        let mut outside = BitGrid::new(self.width, self.height);
+        if self.data.is_empty() {
+            return outside;
+        }
+        let (width, height) = (self.width as i64, self.height as i64);
+        let border = (0..width)
+            .flat_map(|x| [Point::new(x, 0), Point::new(x, height - 1)])
+            .chain((0..height).flat_map(|y| [Point::new(0, y), Point::new(width - 1, y)]))
+            .filter(|&point| passable(&self[point]) && outside.insert(point))
+            .collect();
+        self.fill(border, &mut passable, |point| outside.insert(point));
+
+        let mut enclosed = BitGrid::from_grid(self, &mut passable);
+        for point in outside.iter() {
+            enclosed.set(point, false);
+        }
+        enclosed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+..#..
+.###.
+.#.#.
+.###.
+#...#";
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::parse_str(MAP).unwrap();
+        let filled = grid.flood_fill(Point::new(0, 0), |&c| c == b'.');
+        assert_eq!(filled.count_ones(), 5);
+        assert!(filled.get(Point::new(0, 3)) && !filled.get(Point::new(1, 4)) && !filled.get(Point::new(2, 2)));
+        assert_eq!(grid.flood_fill(Point::new(2, 0), |&c| c == b'.').count_ones(), 0);
+        assert_eq!(grid.flood_fill(Point::new(2, 0), |&c| c == b'#').count_ones(), 9);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = Grid::parse_str(MAP).unwrap();
+        let (labels, sizes) = grid.components(|&c| c == b'#');
+        assert_eq!(sizes, [9, 1, 1]);
+        assert_eq!(labels[Point::new(1, 3)], Some(0));
+        assert_eq!(labels[Point::new(0, 4)], Some(1));
+        assert_eq!(labels[Point::new(4, 4)], Some(2));
+        assert_eq!(labels[Point::new(2, 2)], None);
+        assert_eq!(grid.components(|&c| c == b'.').1, [5, 5, 1, 3]);
+    }
+
+    #[test]
+    fn test_enclosed() {
+        let grid = Grid::parse_str(MAP).unwrap();
+        let enclosed = grid.enclosed(|&c| c == b'.');
+        assert_eq!(enclosed.iter().collect::<Vec<_>>(), [Point::new(2, 2)]);
+        assert_eq!(grid.enclosed(|&c| c == b'#').count_ones(), 0);
+
+        for empty in [Grid::new(0, 3, b'.'), Grid::new(3, 0, b'.')] {
+            assert_eq!(empty.enclosed(|&c| c == b'.').count_ones(), 0);
+            assert_eq!(empty.components(|&c| c == b'.').1, []);
+        }
+    }
+}
@@ -1,7 +1,9 @@
+use crate::error::ParseError;
 use crate::util::point::*;
 use std::ops::{Index, IndexMut};
 use std::fmt::Display;
 
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Grid<T> {
     pub width: usize,
     pub height: usize,
@@ -9,21 +11,11 @@ pub struct Grid<T> {
 }
 
 impl Grid<u8> {
-    pub fn parse(input: &[String]) -> Self {
-        let width = input[0].len();
-        let height = input.len();
-        let mut data = Vec::with_capacity(width * height);
-        input
-            .iter()
-            .for_each(|line| data.extend_from_slice(line.trim().as_bytes()));
-        Grid {
-            width,
-            height,
-            data,
-        }
+    pub fn parse(input: &[String]) -> Result<Self, ParseError> {
+        Grid::parse_with(input, |b| b)
     }
 
-    pub fn parse_str(input: &str) -> Self {
+    pub fn parse_str(input: &str) -> Result<Self, ParseError> {
         Grid::parse(
             &input
                 .lines()
@@ -33,12 +25,215 @@ impl Grid<u8> {
     }
 }
 
-impl<T: PartialEq> Grid<T> {
+/// Width and height of the grid in the input, whose rows are trimmed.
+/// Fails on empty input, blank rows (including trailing ones) and rows of different widths.
+pub fn shape(input: &[String]) -> Result<(usize, usize), ParseError> {
+    let width = match input.first() {
+        Some(line) => line.trim().len(),
+        None => return Err(ParseError::missing_line(0, "expected at least one row")),
+    };
+
+    for (index, line) in input.iter().enumerate() {
+        let row = line.trim();
+        if row.is_empty() {
+            let message = if input[index..].iter().all(|l| l.trim().is_empty()) {
+                "trailing blank line after the grid"
+            } else {
+                "blank row in the grid"
+            };
+            return Err(ParseError::end_of_line(index, line, message));
+        }
+        if row.len() != width {
+            let message = format!("row {} has {} columns, expected {} like row 1", index + 1, row.len(), width);
+            return Err(ParseError::at(index, line, &row[row.len()..], message));
+        }
+    }
+
+    Ok((width, input.len()))
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            data: vec![fill; width * height],
+        }
+    }
+
+    // Copies of the grid, see `GridView` for the versions that do not copy
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        self.view().rotate_clockwise().to_grid()
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        self.view().rotate_counter_clockwise().to_grid()
+    }
+
+    pub fn flip_horizontal(&self) -> Self {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        self.view().flip_vertical().to_grid()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid where each cell is computed from its position, in reading order
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(Point::new(x as i64, y as i64)));
+            }
+        }
+        Grid {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Grid where each byte of the input is mapped through `f`
+    pub fn parse_with(input: &[String], f: impl FnMut(u8) -> T) -> Result<Self, ParseError> {
+        let (width, height) = shape(input)?;
+        let mut data = Vec::with_capacity(width * height);
+        data.extend(input.iter().flat_map(|line| line.trim().bytes()).map(f));
+        Ok(Grid {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Grid where each byte of the input is converted with `TryFrom<u8>`, such as an enum of tiles.
+    /// The error points at the first byte that cannot be converted.
+    pub fn try_parse(input: &[String]) -> Result<Self, ParseError>
+    where
+        T: TryFrom<u8>,
+        T::Error: Display,
+    {
+        let (width, height) = shape(input)?;
+        let mut data = Vec::with_capacity(width * height);
+        for (index, line) in input.iter().enumerate() {
+            let row = line.trim();
+            for (i, byte) in row.bytes().enumerate() {
+                match T::try_from(byte) {
+                    Ok(cell) => data.push(cell),
+                    Err(error) => return Err(ParseError::at(index, line, char_at(row, i), error.to_string())),
+                }
+            }
+        }
+        Ok(Grid {
+            width,
+            height,
+            data,
+        })
+    }
+}
+
+// The character starting at byte `i`, or an empty slice if there is none
+fn char_at(line: &str, i: usize) -> &str {
+    match line.get(i..).and_then(|rest| rest.chars().next()) {
+        Some(c) => &line[i..i + c.len_utf8()],
+        None => &line[line.len()..],
+    }
+}
+
+impl<T> Grid<T> {
     #[inline]
     pub fn contains(&self, point: Point) -> bool {
         point.x >= 0 && point.x < self.width as i64 && point.y >= 0 && point.y < self.height as i64
     }
 
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self[point])
+    }
+
+    // Neighbors in the given directions that are inside the grid
+    fn neighbors<'a>(&'a self, point: Point, directions: &'static [Point]) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        directions
+            .iter()
+            .map(move |&direction| point + direction)
+            .filter(|&neighbor| self.contains(neighbor))
+            .map(|neighbor| (neighbor, &self[neighbor]))
+    }
+
+    /// Up to 4 neighbors of `point` with their values, in the order of `ORTHOGONALS`
+    pub fn orthogonal_neighbors(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors(point, &ORTHOGONALS)
+    }
+
+    /// Up to 8 neighbors of `point` including diagonals, with their values, in the order of `DIAGONALS`
+    pub fn all_neighbors(&self, point: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.neighbors(point, &DIAGONALS)
+    }
+
+    /// Orthogonal neighbors whose value matches `predicate`, such as the open tiles around a point
+    pub fn orthogonal_neighbors_where<'a>(
+        &'a self,
+        point: Point,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.orthogonal_neighbors(point).filter(move |(_, value)| predicate(value))
+    }
+
+    /// Neighbors including diagonals whose value matches `predicate`
+    pub fn all_neighbors_where<'a>(
+        &'a self,
+        point: Point,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.all_neighbors(point).filter(move |(_, value)| predicate(value))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} out of a grid of height {}", y, self.height);
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Rows from top to bottom, which are empty if the width is 0
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Cells of column `x` from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} out of a grid of width {}", x, self.width);
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    /// Columns from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// View of the whole grid, to be transformed without copying
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            width: self.width,
+            height: self.height,
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    /// The grid repeated infinitely in every direction, which needs at least one cell
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        assert!(!self.data.is_empty(), "an empty grid can't be tiled");
+        TiledGrid { grid: self }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
     pub fn find(&self, needle: &T) -> Option<Point> {
         self.data
             .iter()
@@ -74,3 +269,365 @@ impl Display for Grid<u8> {
         Ok(())
     }
 }
+
+// Affine map from the coordinates of a view to the coordinates of the grid: matrix * point + offset
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+struct Transform {
+    matrix: [[i64; 2]; 2],
+    offset: Point,
+}
+
+impl Transform {
+    const IDENTITY: Transform = Transform {
+        matrix: [[1, 0], [0, 1]],
+        offset: ORIGIN,
+    };
+
+    #[inline]
+    fn apply(&self, point: Point) -> Point {
+        let [[a, b], [c, d]] = self.matrix;
+        Point::new(a * point.x + b * point.y, c * point.x + d * point.y) + self.offset
+    }
+
+    // Transform that applies `inner` first and then `self`
+    fn after(&self, inner: &Transform) -> Transform {
+        let [[a, b], [c, d]] = self.matrix;
+        let [[e, f], [g, h]] = inner.matrix;
+        Transform {
+            matrix: [[a * e + b * g, a * f + b * h], [c * e + d * g, c * f + d * h]],
+            offset: self.apply(inner.offset),
+        }
+    }
+}
+
+/// Rotated, flipped or transposed grid that reads from the original grid without copying it.
+/// Transformations can be chained, such as `grid.view().transpose().flip_vertical()`.
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    pub width: usize,
+    pub height: usize,
+    transform: Transform,
+}
+
+// Not derived, as that would require T: Clone
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    // View whose cell at `point` is the cell of this view at `matrix * point + offset`
+    fn then(self, width: usize, height: usize, matrix: [[i64; 2]; 2], offset: Point) -> Self {
+        GridView {
+            grid: self.grid,
+            width,
+            height,
+            transform: self.transform.after(&Transform { matrix, offset }),
+        }
+    }
+
+    /// Rows become columns: the cell at (x, y) is the cell at (y, x)
+    pub fn transpose(self) -> Self {
+        self.then(self.height, self.width, [[0, 1], [1, 0]], ORIGIN)
+    }
+
+    pub fn rotate_clockwise(self) -> Self {
+        self.then(self.height, self.width, [[0, 1], [-1, 0]], Point::new(0, self.height as i64 - 1))
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Self {
+        self.then(self.height, self.width, [[0, -1], [1, 0]], Point::new(self.width as i64 - 1, 0))
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(self) -> Self {
+        self.then(self.width, self.height, [[-1, 0], [0, 1]], Point::new(self.width as i64 - 1, 0))
+    }
+
+    /// Mirrored top to bottom
+    pub fn flip_vertical(self) -> Self {
+        self.then(self.width, self.height, [[1, 0], [0, -1]], Point::new(0, self.height as i64 - 1))
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.x < self.width as i64 && point.y >= 0 && point.y < self.height as i64
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        self.contains(point).then(|| &self.grid[self.transform.apply(point)])
+    }
+
+    /// Cells of row `y` from left to right
+    pub fn row(self, y: usize) -> impl Iterator<Item = &'a T> {
+        (0..self.width).map(move |x| &self.grid[self.transform.apply(Point::new(x as i64, y as i64))])
+    }
+
+    pub fn rows(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Cells of column `x` from top to bottom
+    pub fn column(self, x: usize) -> impl Iterator<Item = &'a T> {
+        (0..self.height).map(move |y| &self.grid[self.transform.apply(Point::new(x as i64, y as i64))])
+    }
+
+    pub fn columns(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Copies the view into a new grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |point| self[point].clone())
+    }
+}
+
+impl<T> Index<Point> for GridView<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        debug_assert!(self.contains(index));
+        &self.grid[self.transform.apply(index)]
+    }
+}
+
+/// Grid repeated infinitely in every direction, so that every point, including negative ones, has a value.
+/// The copy at tile (0, 0) is the grid itself.
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+// Not derived, as that would require T: Clone
+impl<T> Clone for TiledGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TiledGrid<'_, T> {}
+
+impl<'a, T> TiledGrid<'a, T> {
+    /// Point of the original grid that `point` is a copy of
+    #[inline]
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(point.x.rem_euclid(self.grid.width as i64), point.y.rem_euclid(self.grid.height as i64))
+    }
+
+    /// Which copy of the grid `point` is in, such as (-1, 0) for the copy left of the original
+    #[inline]
+    pub fn tile(&self, point: Point) -> Point {
+        Point::new(point.x.div_euclid(self.grid.width as i64), point.y.div_euclid(self.grid.height as i64))
+    }
+
+    /// The 4 neighbors of `point` with their values, in the order of `ORTHOGONALS`
+    pub fn orthogonal_neighbors(self, point: Point) -> impl Iterator<Item = (Point, &'a T)> {
+        ORTHOGONALS
+            .iter()
+            .map(move |&direction| point + direction)
+            .map(move |neighbor| (neighbor, &self.grid[self.wrap(neighbor)]))
+    }
+
+    /// Orthogonal neighbors whose value matches `predicate`
+    pub fn orthogonal_neighbors_where(
+        self,
+        point: Point,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        self.orthogonal_neighbors(point).filter(move |(_, value)| predicate(value))
+    }
+}
+
+impl<T> Index<Point> for TiledGrid<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.grid[self.wrap(index)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum Tile {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<u8> for Tile {
+        type Error = String;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            match value {
+                b'.' => Ok(Tile::Empty),
+                b'#' => Ok(Tile::Wall),
+                _ => Err("unknown tile".to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_constructors() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!((grid.width, grid.height, grid.data.len()), (3, 2, 6));
+
+        let grid = Grid::from_fn(3, 2, |p| p.x + 10 * p.y);
+        assert_eq!(grid.data, vec![0, 1, 2, 10, 11, 12]);
+        assert_eq!(grid[Point::new(1, 1)], 11);
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let lines = ["12", "34"].map(String::from);
+        let grid = Grid::parse_with(&lines, |b| (b - b'0') as u32).unwrap();
+        assert_eq!(grid.data, vec![1, 2, 3, 4]);
+        assert_eq!(Grid::parse(&lines).unwrap().data, b"1234");
+    }
+
+    #[test]
+    fn test_shape() {
+        assert_eq!(shape(&["#..", " ...  "].map(String::from)), Ok((3, 2)));
+
+        let error = Grid::parse_str("#..\n...\n##\n...").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.message, "row 3 has 2 columns, expected 3 like row 1");
+
+        let error = Grid::parse(&[]).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (1, "expected at least one row"));
+
+        let error = Grid::parse_str("#..\n\n...").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (2, "blank row in the grid"));
+
+        let error = Grid::parse(&["#..", "...", "", " "].map(String::from)).unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (3, "trailing blank line after the grid"));
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let grid = Grid::<Tile>::try_parse(&["#.", ".#"].map(String::from)).unwrap();
+        assert_eq!(grid[Point::new(1, 0)], Tile::Empty);
+        assert_eq!(grid[Point::new(1, 1)], Tile::Wall);
+
+        let error = Grid::<Tile>::try_parse(&["#..", ".é"].map(String::from)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "é"));
+        assert_eq!(error.message, "unknown tile");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse_str("123\n456\n789").unwrap();
+        let values = |neighbors: Vec<(Point, &u8)>| neighbors.into_iter().map(|(_, &v)| v as char).collect::<String>();
+
+        assert_eq!(values(grid.orthogonal_neighbors(Point::new(1, 1)).collect()), "2846");
+        assert_eq!(values(grid.orthogonal_neighbors(Point::new(0, 0)).collect()), "42");
+        assert_eq!(values(grid.all_neighbors(Point::new(1, 1)).collect()), "12346789");
+        assert_eq!(values(grid.all_neighbors(Point::new(2, 2)).collect()), "568");
+
+        let odd = grid.all_neighbors_where(Point::new(1, 1), |&v| (v - b'0') % 2 == 1).collect::<Vec<_>>();
+        assert_eq!(odd.iter().map(|&(p, _)| p).collect::<Vec<_>>(), [Point::new(0, 0), Point::new(2, 0), Point::new(0, 2), Point::new(2, 2)]);
+        assert_eq!(values(grid.orthogonal_neighbors_where(Point::new(0, 1), |&v| v > b'4').collect()), "75");
+
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&b'6'));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+    }
+
+    fn lines(grid: &Grid<u8>) -> Vec<String> {
+        grid.rows().map(|row| String::from_utf8(row.to_vec()).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::parse_str("abc\ndef").unwrap();
+        assert_eq!(grid.row(1), b"def");
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"cf");
+        assert_eq!(grid.columns().map(|c| c.copied().collect::<Vec<_>>()).collect::<Vec<_>>(), [b"ad", b"be", b"cf"]);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = Grid::parse_str("abc\ndef").unwrap();
+        assert_eq!(lines(&grid.transpose()), ["ad", "be", "cf"]);
+        assert_eq!(lines(&grid.rotate_clockwise()), ["da", "eb", "fc"]);
+        assert_eq!(lines(&grid.rotate_counter_clockwise()), ["cf", "be", "ad"]);
+        assert_eq!(lines(&grid.flip_horizontal()), ["cba", "fed"]);
+        assert_eq!(lines(&grid.flip_vertical()), ["def", "abc"]);
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse_str("abc\ndef").unwrap();
+        let view = grid.view().rotate_clockwise();
+        assert_eq!((view.width, view.height), (2, 3));
+        assert_eq!(view[Point::new(1, 0)], b'a');
+        assert_eq!(view.get(Point::new(0, 2)), Some(&b'f'));
+        assert_eq!(view.get(Point::new(2, 0)), None);
+        assert_eq!(view.row(1).copied().collect::<Vec<_>>(), b"eb");
+        assert_eq!(view.column(0).copied().collect::<Vec<_>>(), b"def");
+
+        // Chained views match the copies
+        assert_eq!(view.rotate_clockwise().to_grid(), grid.flip_horizontal().flip_vertical());
+        assert_eq!(grid.view().transpose().flip_horizontal().to_grid(), grid.rotate_clockwise());
+        assert_eq!(grid.view().flip_vertical().transpose().to_grid(), grid.rotate_clockwise());
+        assert_eq!(grid.view().rotate_counter_clockwise().flip_vertical().to_grid(), grid.transpose());
+        let rows = grid.view().flip_horizontal().rows().map(|r| r.copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+        assert_eq!(rows, [b"cba", b"fed"]);
+    }
+
+    #[test]
+    fn test_tiled() {
+        let grid = Grid::parse_str("abc\ndef").unwrap();
+        let tiled = grid.tiled();
+        assert_eq!(tiled[Point::new(1, 1)], b'e');
+        assert_eq!(tiled[Point::new(-1, 0)], b'c');
+        assert_eq!(tiled[Point::new(4, -3)], b'e');
+        assert_eq!(tiled.wrap(Point::new(-4, 5)), Point::new(2, 1));
+        assert_eq!(tiled.tile(Point::new(2, 1)), ORIGIN);
+        assert_eq!(tiled.tile(Point::new(-1, 2)), Point::new(-1, 1));
+        assert_eq!(tiled.tile(Point::new(-4, -3)), Point::new(-2, -2));
+
+        let neighbors = tiled.orthogonal_neighbors(ORIGIN).map(|(_, &c)| c).collect::<Vec<_>>();
+        assert_eq!(neighbors, b"ddcb");
+        let neighbors = tiled.orthogonal_neighbors_where(ORIGIN, |&c| c == b'd').map(|(p, _)| p).collect::<Vec<_>>();
+        assert_eq!(neighbors, [UP, DOWN]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(Grid::parse(&[]).is_err());
+        assert!(Grid::parse_str("").is_err());
+
+        let no_columns = Grid::new(0, 2, b'.');
+        assert_eq!(no_columns.rows().collect::<Vec<_>>(), [b"", b""]);
+        assert_eq!(no_columns.columns().count(), 0);
+        assert_eq!(no_columns.find(&b'.'), None);
+
+        let no_rows = Grid::new(2, 0, b'.');
+        assert_eq!(no_rows.rows().count(), 0);
+        assert_eq!(no_rows.column(1).count(), 0);
+        assert_eq!(no_rows.get(ORIGIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of a grid")]
+    fn test_column_out_of_bounds() {
+        Grid::parse_str("ab").unwrap().column(2).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be tiled")]
+    fn test_tiled_empty() {
+        Grid::new(0, 0, b'.').tiled();
+    }
+}
@@ -0,0 +1,137 @@
+use crate::util::point3::*;
+use std::ops::{Index, IndexMut};
+
+/// Dense grid of voxels, the three-dimensional counterpart of `Grid`. Cells are stored x first, then y, then z.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid3<T> {
+    pub width: usize,
+    pub depth: usize,
+    pub height: usize,
+    pub data: Vec<T>,
+}
+
+impl<T: Clone> Grid3<T> {
+    /// Grid of `width` along x, `depth` along y and `height` along z
+    pub fn new(width: usize, depth: usize, height: usize, fill: T) -> Self {
+        Grid3 {
+            width,
+            depth,
+            height,
+            data: vec![fill; width * depth * height],
+        }
+    }
+}
+
+impl<T> Grid3<T> {
+    /// Grid where each cell is computed from its position, x changing fastest and z slowest
+    pub fn from_fn(width: usize, depth: usize, height: usize, mut f: impl FnMut(Point3) -> T) -> Self {
+        let mut data = Vec::with_capacity(width * depth * height);
+        for z in 0..height {
+            for y in 0..depth {
+                for x in 0..width {
+                    data.push(f(Point3::new(x as i64, y as i64, z as i64)));
+                }
+            }
+        }
+        Grid3 {
+            width,
+            depth,
+            height,
+            data,
+        }
+    }
+
+    /// Box of all the points of the grid, empty if any size is 0
+    pub fn bounds(&self) -> Cuboid {
+        Cuboid {
+            min: ORIGIN_3D,
+            max: Point3::new(self.width as i64 - 1, self.depth as i64 - 1, self.height as i64 - 1),
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point3) -> bool {
+        point.x >= 0
+            && point.x < self.width as i64
+            && point.y >= 0
+            && point.y < self.depth as i64
+            && point.z >= 0
+            && point.z < self.height as i64
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point3) -> Option<&T> {
+        self.contains(point).then(|| &self[point])
+    }
+
+    /// Up to 6 neighbors of `point` with their values, in the order of `ORTHOGONALS_3D`
+    pub fn orthogonal_neighbors(&self, point: Point3) -> impl Iterator<Item = (Point3, &T)> + '_ {
+        point
+            .orthogonal_neighbors()
+            .filter(|&neighbor| self.contains(neighbor))
+            .map(|neighbor| (neighbor, &self[neighbor]))
+    }
+
+    /// Sets every cell of `cuboid` that is inside the grid to `value`
+    pub fn fill(&mut self, cuboid: &Cuboid, value: T)
+    where
+        T: Clone,
+    {
+        if let Some(cuboid) = cuboid.intersection(&self.bounds()) {
+            for point in cuboid.points() {
+                self[point] = value.clone();
+            }
+        }
+    }
+
+    #[inline]
+    fn index_of(&self, point: Point3) -> usize {
+        debug_assert!(self.contains(point));
+        (point.z as usize * self.depth + point.y as usize) * self.width + point.x as usize
+    }
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point3) -> &Self::Output {
+        &self.data[self.index_of(index)]
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Point3) -> &mut Self::Output {
+        let index = self.index_of(index);
+        &mut self.data[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid3() {
+        let grid = Grid3::from_fn(2, 3, 4, |p| p.x + 10 * p.y + 100 * p.z);
+        assert_eq!(grid.data.len(), 24);
+        assert_eq!(grid[Point3::new(1, 2, 3)], 321);
+        assert_eq!(grid.get(Point3::new(2, 0, 0)), None);
+        assert_eq!(grid.bounds().volume(), 24);
+        assert_eq!(Grid3::new(0, 3, 3, 0).bounds().volume(), 0);
+
+        let neighbors = grid.orthogonal_neighbors(ORIGIN_3D).map(|(_, &v)| v).collect::<Vec<_>>();
+        assert_eq!(neighbors, [1, 10, 100]);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut grid = Grid3::new(3, 3, 3, 0);
+        grid.fill(&Cuboid::new(Point3::new(1, 1, 1), Point3::new(5, 1, 1)), 1);
+        assert_eq!(grid.data.iter().sum::<i32>(), 2);
+        grid[Point3::new(0, 0, 2)] = 7;
+        assert_eq!(grid.get(Point3::new(0, 0, 2)), Some(&7));
+        assert_eq!(grid.orthogonal_neighbors(Point3::new(1, 1, 2)).filter(|(_, &v)| v == 1).count(), 1);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Red, green and blue components of a pixel
+pub type Rgb = [u8; 3];
+
+/// Picture of a grid, one pixel per cell until scaled, for maps too large to read in a terminal.
+/// Written as PPM, or as PNG with the `png` feature.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    /// Image with each cell drawn in the color `color` maps it to
+    pub fn to_image(&self, mut color: impl FnMut(&T) -> Rgb) -> Image {
+        Image {
+            width: self.width,
+            height: self.height,
+            pixels: self.data.iter().map(&mut color).collect(),
+        }
+    }
+}
+
+impl Image {
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.x < self.width as i64 && point.y >= 0 && point.y < self.height as i64
+    }
+
+    pub fn pixel(&self, point: Point) -> Rgb {
+        self.pixels[point.y as usize * self.width + point.x as usize]
+    }
+
+    /// Draws the points of a path, such as the route of a search, in `color`. Points outside the image are skipped.
+    pub fn path(mut self, points: impl IntoIterator<Item = Point>, color: Rgb) -> Self {
+        for point in points {
+            if self.contains(point) {
+                self.pixels[point.y as usize * self.width + point.x as usize] = color;
+            }
+        }
+        self
+    }
+
+    /// Each pixel becomes a square of `factor` by `factor` pixels
+    pub fn scaled(&self, factor: usize) -> Image {
+        let (width, height) = (self.width * factor, self.height * factor);
+        let pixels = (0..width * height)
+            .map(|i| self.pixels[i / width / factor * self.width + i % width / factor])
+            .collect();
+        Image { width, height, pixels }
+    }
+
+    /// Writes the image as a binary PPM (P6)
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.pixels.concat()).map_err(io::Error::other)
+    }
+
+    /// Saves the image as PNG if the path ends in `.png`, PPM otherwise
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let png = path.extension().is_some_and(|extension| extension == "png");
+        if png && cfg!(not(feature = "png")) {
+            return Err(io::Error::other("PNG export requires the `png` feature"));
+        }
+
+        let mut out = BufWriter::new(File::create(path)?);
+        #[cfg(feature = "png")]
+        if png {
+            self.write_png(&mut out)?;
+            return out.flush();
+        }
+        self.write_ppm(&mut out)?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Rgb = [0, 0, 0];
+    const WHITE: Rgb = [255, 255, 255];
+    const RED: Rgb = [255, 0, 0];
+
+    fn image() -> Image {
+        let grid = Grid::parse_str("#.\n..").unwrap();
+        grid.to_image(|&c| if c == b'#' { BLACK } else { WHITE })
+            .path([Point::new(1, 0), Point::new(1, 1), Point::new(1, 2)], RED)
+    }
+
+    #[test]
+    fn test_image() {
+        let image = image();
+        assert_eq!(image.pixel(Point::new(0, 0)), BLACK);
+        assert_eq!(image.pixel(Point::new(1, 1)), RED);
+        assert_eq!(image.pixel(Point::new(0, 1)), WHITE);
+
+        let scaled = image.scaled(3);
+        assert_eq!((scaled.width, scaled.height), (6, 6));
+        assert_eq!(scaled.pixel(Point::new(2, 2)), BLACK);
+        assert_eq!(scaled.pixel(Point::new(3, 5)), RED);
+        assert_eq!(scaled.pixel(Point::new(2, 3)), WHITE);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut ppm = Vec::new();
+        image().write_ppm(&mut ppm).unwrap();
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([BLACK, RED, WHITE, RED].concat());
+        assert_eq!(ppm, expected);
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn test_png() {
+        let mut png = Vec::new();
+        image().write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, [BLACK, RED, WHITE, RED].concat());
+    }
+}
@@ -0,0 +1,340 @@
+use std::ops::{Range, RangeInclusive};
+
+/// Half-open interval of integers from `start` included to `end` excluded, empty when `end <= start`.
+/// Closed intervals are built with `closed` or from a `RangeInclusive`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// Interval from `first` to `last`, both included
+    pub const fn closed(first: i64, last: i64) -> Self {
+        Interval::new(first, last + 1)
+    }
+
+    pub const fn with_length(start: i64, length: i64) -> Self {
+        Interval::new(start, start + length)
+    }
+
+    /// Number of integers in the interval
+    #[inline]
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Last integer of the interval, meaningless if it is empty
+    #[inline]
+    pub fn last(&self) -> i64 {
+        self.end - 1
+    }
+
+    #[inline]
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Integers in both intervals, `None` if they don't overlap
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let intersection = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    pub fn intersects(&self, other: &Interval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    #[must_use]
+    pub fn translated(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl From<RangeInclusive<i64>> for Interval {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Interval::closed(*range.start(), *range.end())
+    }
+}
+
+/// Union of intervals, kept as sorted, disjoint and non-adjacent non-empty intervals so that equal sets compare
+/// equal.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// The normalized intervals of the set, in increasing order
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Smallest integer of the set
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// Largest integer of the set
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(Interval::last)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        // Index of the first interval ending after the value, the only one that can contain it
+        let index = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if !interval.is_empty() {
+            *self = self.intervals.iter().copied().chain([interval]).collect();
+        }
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals.iter().chain(&other.intervals).copied().collect()
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            // The interval ending first can't overlap anything further in the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Integers of `self` that are not in `other`
+    #[must_use]
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        match (self.min(), self.max()) {
+            (Some(min), Some(max)) => self.intersection(&other.complement(Interval::closed(min, max))),
+            _ => IntervalSet::new(),
+        }
+    }
+
+    /// Integers of `universe` that are not in the set
+    #[must_use]
+    pub fn complement(&self, universe: Interval) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut start = universe.start;
+        for interval in &self.intervals {
+            intervals.push(Interval::new(start, interval.start.min(universe.end)));
+            start = start.max(interval.end);
+        }
+        intervals.push(Interval::new(start, universe.end));
+        intervals.into_iter().collect()
+    }
+
+    #[must_use]
+    pub fn translated(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self.intervals.iter().map(|interval| interval.translated(offset)).collect(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted = iter.into_iter().filter(|interval| !interval.is_empty()).collect::<Vec<_>>();
+        sorted.sort_unstable_by_key(|interval| interval.start);
+
+        // Merge the intervals that overlap or touch the previous one
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+/// Function on integers that adds a constant offset to the values of each of its disjoint pieces, and leaves the
+/// values outside of all pieces unchanged
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct IntervalMap {
+    // Disjoint pieces sorted by source, with their offset
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl IntervalMap {
+    pub fn new() -> Self {
+        IntervalMap::default()
+    }
+
+    /// Maps the values of `source` to `value + offset`. Values that already belong to a piece keep their mapping.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        let remaining = IntervalSet::from(source).difference(&self.domain());
+        self.pieces.extend(remaining.intervals.into_iter().map(|interval| (interval, offset)));
+        self.pieces.sort_unstable_by_key(|(interval, _)| interval.start);
+    }
+
+    /// Values that belong to a piece
+    pub fn domain(&self) -> IntervalSet {
+        self.pieces.iter().map(|&(interval, _)| interval).collect()
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|(interval, _)| interval.end <= value);
+        match self.pieces.get(index) {
+            Some((interval, offset)) if interval.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Map applying `self` then `next`, so that `self.then(next).get(v) == next.get(self.get(v))`
+    #[must_use]
+    pub fn then(&self, next: &IntervalMap) -> IntervalMap {
+        let mut composed = IntervalMap::new();
+        for &(source, offset) in &self.pieces {
+            let image = source.translated(offset);
+            for &(next_source, next_offset) in &next.pieces {
+                if let Some(overlap) = image.intersection(&next_source) {
+                    composed.insert(overlap.translated(-offset), offset + next_offset);
+                }
+            }
+            // Values of the piece that `next` leaves unchanged
+            composed.insert(source, offset);
+        }
+        // Values that only `next` changes
+        for &(next_source, next_offset) in &next.pieces {
+            composed.insert(next_source, next_offset);
+        }
+        composed
+    }
+
+    /// Image of every value of `set`
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let unchanged = set.difference(&self.domain()).intervals;
+        let mapped = self.pieces.iter().flat_map(|&(source, offset)| {
+            set.intervals
+                .iter()
+                .filter_map(move |interval| interval.intersection(&source))
+                .map(move |interval| interval.translated(offset))
+        });
+        unchanged.into_iter().chain(mapped).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::from(2..=5);
+        assert_eq!((a, a.len(), a.last()), (Interval::new(2, 6), 4, 5));
+        assert!(a.contains(5) && !a.contains(6));
+        assert_eq!(a.intersection(&Interval::with_length(4, 10)), Some(Interval::new(4, 6)));
+        assert!(!a.intersects(&Interval::from(6..8)));
+        assert!(Interval::new(3, 1).is_empty());
+        assert_eq!(Interval::new(3, 1).len(), 0);
+    }
+
+    #[test]
+    fn test_normalization() {
+        let s = set(&[(5, 8), (0, 2), (1, 3), (8, 9), (20, 20)]);
+        assert_eq!(s.intervals(), [Interval::new(0, 3), Interval::new(5, 9)]);
+        assert_eq!((s.len(), s.min(), s.max()), (7, Some(0), Some(8)));
+        assert!(s.contains(2) && !s.contains(3) && s.contains(8) && !s.contains(9));
+
+        let mut t = s.clone();
+        t.insert(Interval::new(3, 5));
+        assert_eq!(t, set(&[(0, 9)]));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(a.complement(Interval::new(-5, 25)), set(&[(-5, 0), (10, 20)]));
+        assert_eq!(IntervalSet::new().complement(Interval::new(1, 4)), set(&[(1, 4)]));
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.translated(-10), set(&[(-10, 0), (10, 20)]));
+    }
+
+    #[test]
+    fn test_interval_map() {
+        let mut map = IntervalMap::new();
+        map.insert(Interval::with_length(98, 2), 50 - 98);
+        map.insert(Interval::with_length(50, 48), 52 - 50);
+        // Overlaps the first piece, which keeps its mapping
+        map.insert(Interval::new(90, 100), 1000);
+        assert_eq!([79, 14, 55, 13, 98, 99, 100, 95].map(|v| map.get(v)), [81, 14, 57, 13, 50, 51, 100, 97]);
+        assert_eq!(map.domain(), set(&[(50, 100)]));
+
+        let image = map.map_set(&set(&[(79, 93), (55, 68)]));
+        assert_eq!(image, set(&[(57, 70), (81, 95)]));
+        assert_eq!(map.map_set(&set(&[(40, 60), (97, 105)])), set(&[(40, 62), (99, 105)]));
+    }
+
+    #[test]
+    fn test_then() {
+        let mut first = IntervalMap::new();
+        first.insert(Interval::new(0, 10), 5);
+        first.insert(Interval::new(20, 25), -20);
+        let mut second = IntervalMap::new();
+        second.insert(Interval::new(8, 12), 100);
+        second.insert(Interval::new(30, 40), -1);
+
+        let composed = first.then(&second);
+        for value in -5..50 {
+            assert_eq!(composed.get(value), second.get(first.get(value)), "value {}", value);
+        }
+        let seeds = set(&[(-5, 50)]);
+        assert_eq!(composed.map_set(&seeds), second.map_set(&first.map_set(&seeds)));
+    }
+}
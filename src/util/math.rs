@@ -0,0 +1,133 @@
+/// Greatest common divisor, 0 only if both are 0
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` if it overflows
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Extended Euclid: `(g, x, y)` such that `g = gcd(a, b) = a * x + b * y`, with `g` non-negative
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` such that `a * x` is 1 modulo `modulus`, `None` if they aren't coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Chinese Remainder Theorem: solves `x = residue (mod modulus)` for all the `(residue, modulus)` pairs. The moduli
+/// must be positive but don't need to be coprime. Returns the smallest non-negative solution and the least common
+/// multiple of the moduli, after which solutions repeat, or `None` if the congruences contradict each other or the
+/// result overflows.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold((0, 1), |(residue, modulus), &(other_residue, other_modulus)| {
+        // Find x = residue + modulus * k that also solves the other congruence, which needs
+        // modulus * k = other_residue - residue (mod other_modulus). Computed in i128, where none of the differences
+        // and products below can overflow.
+        let g = gcd(modulus as u64, other_modulus as u64) as i128;
+        let difference = other_residue as i128 - residue as i128;
+        if difference % g != 0 {
+            return None;
+        }
+
+        // Dividing everything by g leaves modulus / g invertible modulo the reduced modulus
+        let reduced = other_modulus as i128 / g;
+        let inverse = mod_inverse((modulus as i128 / g) as i64, reduced as i64)? as i128;
+        let k = (difference / g).rem_euclid(reduced) * inverse % reduced;
+        let combined = i64::try_from(lcm(modulus as u64, other_modulus as u64)?).ok()?;
+        let x = (residue as i128 + modulus as i128 * k).rem_euclid(combined as i128);
+        Some((x as i64, combined))
+    })
+}
+
+/// First number of all the sequences `first + k * period`, given as `(first, period)` pairs with positive periods,
+/// `None` if there is none, no sequence or it overflows
+pub fn first_common(sequences: &[(i64, i64)]) -> Option<i64> {
+    let (residue, period) = crt(sequences)?;
+    // The first solution where every sequence already started
+    let earliest = sequences.iter().map(|&(first, _)| first).max()?;
+    let gap = (earliest - residue).max(0);
+    gap.checked_add(period - 1)
+        .and_then(|rounded| (rounded / period).checked_mul(period))
+        .and_then(|offset| residue.checked_add(offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!((gcd(12, 18), gcd(0, 5), gcd(7, 0)), (6, 5, 7));
+        assert_eq!((lcm(4, 6), lcm(0, 6)), (Some(12), Some(0)));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm(u64::MAX, 3), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b, gcd) in [(240, 46, 2), (-240, 46, 2), (17, 5, 1), (0, 9, 9), (9, 0, 9), (-4, -6, 2)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli with common factors
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(3, 4), (4, 6)]), None);
+        // Residues outside of the moduli or negative
+        assert_eq!(crt(&[(-1, 4), (11, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, i64::MAX), (0, 2)]), None);
+
+        let large = [(3739, 3739), (3761, 3761), (3797, 3797), (3889, 3889)];
+        assert_eq!(crt(&large), Some((0, 3739 * 3761 * 3797 * 3889)));
+
+        // Residues whose difference overflows i64
+        let reduced = crt(&[(i64::MIN.rem_euclid(3), 3), (i64::MAX % 5, 5)]);
+        assert_eq!(crt(&[(i64::MIN, 3), (i64::MAX, 5)]), reduced);
+        assert_eq!(crt(&[(i64::MAX, i64::MAX - 1), (i64::MIN, i64::MAX)]), None);
+    }
+
+    #[test]
+    fn test_first_common() {
+        // Sequences starting at their period, as in the puzzle inputs
+        assert_eq!(first_common(&[(4, 4), (6, 6)]), Some(12));
+        // Offset sequences: 3, 7, 11 and 5, 11
+        assert_eq!(first_common(&[(3, 4), (5, 6)]), Some(11));
+        assert_eq!(first_common(&[(15, 4), (5, 6)]), Some(23));
+        assert_eq!(first_common(&[(3, 4), (4, 6)]), None);
+        assert_eq!(first_common(&[]), None);
+        assert_eq!(first_common(&[(i64::MAX, 3), (0, 2)]), None);
+    }
+}
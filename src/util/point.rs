@@ -1,3 +1,6 @@
+// The operators are #[must_use], which recent compilers ignore on trait impls and warn about
+#![allow(unused_attributes)]
+
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul};
 
 pub const ORIGIN: Point = Point::new(0, 0);
@@ -48,20 +51,99 @@ impl Point {
     }
 }
 
-impl From<u8> for Point {
+/// One of the four orthogonal directions, in clockwise order starting up. Up is towards negative y.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    #[inline]
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
     #[inline]
     #[must_use]
-    fn from(value: u8) -> Self {
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    #[inline]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+impl From<Direction> for Point {
+    #[inline]
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => UP,
+            Direction::Right => RIGHT,
+            Direction::Down => DOWN,
+            Direction::Left => LEFT,
+        }
+    }
+}
+
+impl TryFrom<Point> for Direction {
+    type Error = &'static str;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        match point {
+            UP => Ok(Direction::Up),
+            RIGHT => Ok(Direction::Right),
+            DOWN => Ok(Direction::Down),
+            LEFT => Ok(Direction::Left),
+            _ => Err("not a unit step"),
+        }
+    }
+}
+
+/// Reads arrows (`^>v<`), letters (`UDLR` or compass points `NSEW`) and the digit codes of day 18, where `0` is
+/// right and the following digits go clockwise.
+impl TryFrom<u8> for Direction {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            b'>' | b'R' => RIGHT,
-            b'<' | b'L' => LEFT,
-            b'^' | b'U' => UP,
-            b'v' | b'D' => DOWN,
-            _ => panic!("Unknown direction: {}", value),
+            b'^' | b'U' | b'N' | b'3' => Ok(Direction::Up),
+            b'>' | b'R' | b'E' | b'0' => Ok(Direction::Right),
+            b'v' | b'D' | b'S' | b'1' => Ok(Direction::Down),
+            b'<' | b'L' | b'W' | b'2' => Ok(Direction::Left),
+            _ => Err("unknown direction"),
         }
     }
 }
 
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + Point::from(rhs)
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
 impl Add for Point {
     type Output = Self;
 
@@ -105,3 +187,32 @@ impl Mul<i64> for Point {
         Point::new(self.x * rhs, self.y * rhs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(Point::from(direction)), Ok(direction));
+            // With y pointing down, turning right is counter-clockwise in the usual axes
+            assert_eq!(Point::from(direction.turn_right()), Point::from(direction).counter_clockwise());
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+        assert!(Direction::try_from(Point::new(1, 1)).is_err());
+        assert_eq!(Point::new(2, 3) + Direction::Up, Point::new(2, 2));
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        for codes in ["^UN3", ">RE0", "vDS1", "<LW2"] {
+            let directions = codes.bytes().map(|b| Direction::try_from(b).unwrap()).collect::<Vec<_>>();
+            assert!(directions.iter().all(|&d| d == directions[0]));
+        }
+        assert_eq!(Direction::try_from(b'x'), Err("unknown direction"));
+    }
+}
@@ -0,0 +1,229 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul};
+
+pub const ORIGIN_3D: Point3 = Point3::new(0, 0, 0);
+// Positive z is up
+pub const ABOVE: Point3 = Point3::new(0, 0, 1);
+pub const BELOW: Point3 = Point3::new(0, 0, -1);
+pub const ORTHOGONALS_3D: [Point3; 6] = [
+    Point3::new(-1, 0, 0),
+    Point3::new(1, 0, 0),
+    Point3::new(0, -1, 0),
+    Point3::new(0, 1, 0),
+    BELOW,
+    ABOVE,
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn manhattan_distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The 6 points sharing a face with this one, in the order of `ORTHOGONALS_3D`
+    pub fn orthogonal_neighbors(self) -> impl Iterator<Item = Point3> {
+        ORTHOGONALS_3D.into_iter().map(move |direction| self + direction)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Point3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Point3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// Axis-aligned box of the points from `min` to `max`, both included
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Cuboid {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Cuboid {
+    /// Box between two opposite corners, in any order
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Cuboid {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Size along each axis
+    pub fn size(&self) -> Point3 {
+        self.max - self.min + Point3::new(1, 1, 1)
+    }
+
+    /// Number of points in the box
+    pub fn volume(&self) -> i64 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    /// Points in both boxes, `None` if they don't overlap
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let (min, max) = (self.min.max(other.min), self.max.min(other.max));
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Cuboid { min, max })
+    }
+
+    pub fn intersects(&self, other: &Cuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    #[must_use]
+    pub fn translated(&self, offset: Point3) -> Cuboid {
+        Cuboid {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Points of the box, x changing fastest and z slowest
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min, self.max);
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(-4, 5, 6);
+        assert_eq!(a + b, Point3::new(-3, 3, 9));
+        assert_eq!(a - b, Point3::new(5, -7, -3));
+        assert_eq!(a * 2, Point3::new(2, -4, 6));
+        assert_eq!(a.manhattan_distance(&b), 15);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        let neighbors = ORIGIN_3D.orthogonal_neighbors().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 6);
+        assert!(neighbors.iter().all(|n| n.manhattan_distance(&ORIGIN_3D) == 1));
+        assert!(neighbors.contains(&ABOVE) && neighbors.contains(&BELOW));
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let a = Cuboid::new(Point3::new(2, 0, 5), Point3::new(0, 1, 5));
+        assert_eq!(a.min, Point3::new(0, 0, 5));
+        assert_eq!((a.size(), a.volume()), (Point3::new(3, 2, 1), 6));
+        assert!(a.contains(Point3::new(1, 1, 5)) && !a.contains(Point3::new(1, 1, 4)));
+        assert_eq!(a.points().count(), 6);
+        assert_eq!(a.points().nth(4), Some(Point3::new(1, 1, 5)));
+
+        let b = Cuboid::new(Point3::new(1, 1, 0), Point3::new(1, 3, 9));
+        assert_eq!(a.intersection(&b), Some(Cuboid::new(Point3::new(1, 1, 5), Point3::new(1, 1, 5))));
+        assert!(!a.intersects(&b.translated(Point3::new(2, 0, 0))));
+        assert!(!a.intersects(&a.translated(BELOW)));
+    }
+
+    // Flat boxes on the same plane, such as the bricks of day 22 seen from above
+    #[test]
+    fn test_cuboid_intersects() {
+        let flat = |(x1, y1): (i64, i64), (x2, y2): (i64, i64)| {
+            Cuboid::new(Point3::new(x1, y1, 0), Point3::new(x2, y2, 0))
+        };
+        let cases = [
+            // A point on the edge and in the middle of a line, both ways around, and two equal points
+            ([(0, 0), (0, 0)], [(0, 0), (2, 0)], true),
+            ([(1, 0), (1, 0)], [(0, 0), (2, 0)], true),
+            ([(0, 0), (2, 0)], [(0, 0), (0, 0)], true),
+            ([(0, 0), (2, 0)], [(1, 0), (1, 0)], true),
+            ([(1, 0), (1, 0)], [(1, 0), (1, 0)], true),
+            // Parallel lines that are not collinear
+            ([(1, 0), (1, 2)], [(2, 0), (2, 2)], false),
+            // Collinear lines overlapping at their ends and in the middle, horizontal then vertical
+            ([(0, 0), (2, 0)], [(2, 0), (3, 0)], true),
+            ([(0, 0), (2, 0)], [(1, 0), (3, 0)], true),
+            ([(0, 0), (0, 2)], [(0, 2), (0, 3)], true),
+            ([(0, 0), (0, 2)], [(0, 1), (0, 3)], true),
+            // Collinear lines apart
+            ([(0, 0), (2, 0)], [(3, 0), (5, 0)], false),
+            ([(0, 0), (0, 2)], [(0, 3), (0, 5)], false),
+            // Perpendicular lines sharing an end, equal lines, an end touching a middle and two middles
+            ([(0, 0), (0, 2)], [(0, 2), (2, 2)], true),
+            ([(0, 0), (0, 2)], [(0, 0), (0, 2)], true),
+            ([(0, 0), (0, 2)], [(0, 1), (2, 1)], true),
+            ([(2, 0), (2, 2)], [(0, 1), (2, 1)], true),
+            ([(0, 1), (2, 1)], [(1, 0), (1, 2)], true),
+        ];
+        for ([a1, a2], [b1, b2], expected) in cases {
+            assert_eq!(flat(a1, a2).intersects(&flat(b1, b2)), expected, "{:?} and {:?}", (a1, a2), (b1, b2));
+        }
+    }
+}
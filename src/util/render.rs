@@ -0,0 +1,197 @@
+use crate::util::grid::Grid;
+use crate::util::point::*;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::IsTerminal;
+
+/// The 8 basic ANSI foreground colors
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+/// How a cell is drawn. Anything left unset keeps the look of the layers below, such as the cell's own glyph.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Style {
+    glyph: Option<char>,
+    color: Option<Color>,
+    bold: bool,
+}
+
+impl Style {
+    pub const fn new() -> Self {
+        Style {
+            glyph: None,
+            color: None,
+            bold: false,
+        }
+    }
+
+    /// Draws `glyph` instead of the cell's own glyph
+    pub const fn glyph(self, glyph: char) -> Self {
+        Style { glyph: Some(glyph), ..self }
+    }
+
+    pub const fn color(self, color: Color) -> Self {
+        Style { color: Some(color), ..self }
+    }
+
+    pub const fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    // This style drawn over `under`
+    fn over(self, under: Style) -> Style {
+        Style {
+            glyph: self.glyph.or(under.glyph),
+            color: self.color.or(under.color),
+            bold: self.bold || under.bold,
+        }
+    }
+}
+
+// Style of a cell from its position and value
+type CellStyle<'a, T> = Box<dyn Fn(Point, &T) -> Style + 'a>;
+
+enum Layer<'a, T> {
+    Points(HashMap<Point, Style>),
+    Cells(CellStyle<'a, T>),
+}
+
+/// Drawing of a grid with highlighted points, paths and styled cells on top, created by `Grid::render`.
+/// Layers are drawn in the order they are added. It displays as plain text unless ANSI colors are enabled:
+///
+/// ```ignore
+/// eprint!("{}", grid.render(|&c| c as char).highlight(seen, Style::new().color(Color::Red)).ansi_if_terminal());
+/// ```
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Box<dyn Fn(&T) -> char + 'a>,
+    layers: Vec<Layer<'a, T>>,
+    ansi: bool,
+}
+
+impl<T> Grid<T> {
+    /// Renderer drawing each cell as the character `glyph` maps it to
+    pub fn render<'a>(&'a self, glyph: impl Fn(&T) -> char + 'a) -> Render<'a, T> {
+        Render {
+            grid: self,
+            glyph: Box::new(glyph),
+            layers: Vec::new(),
+            ansi: false,
+        }
+    }
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Draws the given points with `style`
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, style: Style) -> Self {
+        let points = points.into_iter().map(|point| (point, style)).collect();
+        self.layers.push(Layer::Points(points));
+        self
+    }
+
+    /// Draws a path of adjacent points as arrows towards the next point, in the color of `style`.
+    /// The last point is drawn with `style` only.
+    pub fn path(mut self, points: impl IntoIterator<Item = Point>, style: Style) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let mut styles = HashMap::new();
+        for (i, &point) in points.iter().enumerate() {
+            let arrow = match points.get(i + 1).map(|&next| next - point) {
+                Some(UP) => Some('^'),
+                Some(DOWN) => Some('v'),
+                Some(LEFT) => Some('<'),
+                Some(RIGHT) => Some('>'),
+                _ => None,
+            };
+            styles.insert(point, arrow.map_or(style, |arrow| style.glyph(arrow)));
+        }
+        self.layers.push(Layer::Points(styles));
+        self
+    }
+
+    /// Draws every cell with the style `style` gives for its position and value
+    pub fn style_cells(mut self, style: impl Fn(Point, &T) -> Style + 'a) -> Self {
+        self.layers.push(Layer::Cells(Box::new(style)));
+        self
+    }
+
+    /// Whether to color the output with ANSI escape codes, plain text otherwise
+    pub fn ansi(self, ansi: bool) -> Self {
+        Render { ansi, ..self }
+    }
+
+    /// Colors the output only when stderr, where diagnostics go, is a terminal
+    pub fn ansi_if_terminal(self) -> Self {
+        let ansi = std::io::stderr().is_terminal();
+        self.ansi(ansi)
+    }
+
+    fn style(&self, point: Point) -> Style {
+        let value = &self.grid[point];
+        self.layers.iter().fold(Style::new(), |under, layer| match layer {
+            Layer::Points(styles) => styles.get(&point).map_or(under, |style| style.over(under)),
+            Layer::Cells(style) => style(point, value).over(under),
+        })
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height as i64 {
+            for x in 0..self.grid.width as i64 {
+                let point = Point::new(x, y);
+                let style = self.style(point);
+                let glyph = style.glyph.unwrap_or_else(|| (self.glyph)(&self.grid[point]));
+
+                let mut codes = Vec::new();
+                if style.bold {
+                    codes.push(1);
+                }
+                if let Some(color) = style.color {
+                    codes.push(30 + color as u8);
+                }
+                if !self.ansi || codes.is_empty() {
+                    write!(f, "{}", glyph)?;
+                } else {
+                    let codes = codes.iter().map(u8::to_string).collect::<Vec<_>>();
+                    write!(f, "\x1b[{}m{}\x1b[0m", codes.join(";"), glyph)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain() {
+        let grid = Grid::parse_str("...\n.#.\n...").unwrap();
+        let render = grid
+            .render(|&c| if c == b'#' { '█' } else { ' ' })
+            .style_cells(|point, _| if point.y == 2 { Style::new().glyph('~') } else { Style::new() })
+            .path([ORIGIN, RIGHT, Point::new(2, 0), Point::new(2, 1)], Style::new().glyph('E'))
+            .highlight([Point::new(0, 1)], Style::new().glyph('*'));
+        assert_eq!(render.to_string(), ">>v\n*█E\n~~~\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        let grid = Grid::parse_str("ab").unwrap();
+        let red = Style::new().color(Color::Red);
+        let render = grid.render(|&c| c as char).highlight([ORIGIN], red).highlight([ORIGIN, RIGHT], Style::new().bold());
+        assert_eq!(render.to_string(), "ab\n");
+        assert_eq!(render.ansi(true).to_string(), "\x1b[1;31ma\x1b[0m\x1b[1mb\x1b[0m\n");
+    }
+}
@@ -0,0 +1,232 @@
+use crate::util::grid::Grid;
+use crate::util::point::*;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cost of a move in Dijkstra and A*, `Default` is zero
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Number of moves from `start` to every reachable state
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+// States found by a search, with the cheapest known cost to reach them and the index of the state they are
+// reached from. The start is at index 0 and is its own parent.
+struct Explored<S, C> {
+    states: Vec<(S, C, usize)>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Explored<S, C> {
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.states[index].0.clone()];
+        while index != 0 {
+            index = self.states[index].2;
+            path.push(self.states[index].0.clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+// A* search, which is Dijkstra when `heuristic` is always zero. Stops at the first state matching `success`,
+// returning its index, or explores every reachable state if none does.
+fn search<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut success: impl FnMut(&S) -> bool,
+) -> (Explored<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut explored = Explored {
+        states: vec![(start.clone(), C::default(), 0)],
+        indices: HashMap::from([(start.clone(), 0)]),
+    };
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // Skip states that were reached more cheaply after being queued
+        if cost > explored.states[index].1 {
+            continue;
+        }
+        let state = explored.states[index].0.clone();
+        if success(&state) {
+            return (explored, Some(index));
+        }
+
+        for (next, move_cost) in successors(&state) {
+            let next_cost = cost + move_cost;
+            let next_index = match explored.indices.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(explored.states.len());
+                    explored.states.push((next.clone(), next_cost, index));
+                    explored.states.len() - 1
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= explored.states[next_index].1 {
+                        continue;
+                    }
+                    explored.states[next_index] = (next.clone(), next_cost, index);
+                    next_index
+                }
+            };
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next_index)));
+        }
+    }
+
+    (explored, None)
+}
+
+/// Cheapest path from `start` to a state matching `success`, including both ends, and its cost
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    success: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Cost of the cheapest path from `start` to every reachable state
+pub fn dijkstra_all<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (explored, _) = search(start, successors, |_| C::default(), |_| false);
+    explored.states.into_iter().map(|(state, cost, _)| (state, cost)).collect()
+}
+
+/// Like `dijkstra`, exploring first the states that `heuristic` estimates closer to success. The heuristic
+/// must never overestimate the remaining cost for the path to be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    success: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (explored, found) = search(start, successors, heuristic, success);
+    found.map(|index| (explored.path(index), explored.states[index].1))
+}
+
+/// Number of orthogonal steps from `start` to every cell reachable through cells matching `open`
+pub fn grid_bfs<T>(grid: &Grid<T>, start: Point, mut open: impl FnMut(&T) -> bool) -> HashMap<Point, usize> {
+    bfs(start, |&point| {
+        grid.orthogonal_neighbors_where(point, &mut open)
+            .map(|(neighbor, _)| neighbor)
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Cheapest orthogonal path between two cells, where `cost` is the cost of entering a cell, or `None` if it
+/// can't be entered. Costs must be at least 1, the search is guided by the Manhattan distance to `goal`.
+pub fn grid_shortest_path<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Point,
+    mut cost: impl FnMut(&T) -> Option<u32>,
+) -> Option<(Vec<Point>, u32)> {
+    astar(
+        start,
+        |&point| {
+            ORTHOGONALS
+                .iter()
+                .filter_map(|&direction| {
+                    let next = point + direction;
+                    Some((next, cost(grid.get(next)?)?))
+                })
+                .collect::<Vec<_>>()
+        },
+        |point| point.manhattan_distance(&goal) as u32,
+        |&point| point == goal,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each number n leads to n + 1 for a cost of 2 and to 2 * n for a cost of n
+    fn successors(&n: &u32) -> Vec<(u32, u32)> {
+        vec![(n + 1, 2), (2 * n, n)]
+    }
+
+    #[test]
+    fn test_bfs() {
+        let distances = bfs(1, |&n: &u32| [n + 1, n * 3].into_iter().filter(|&n| n <= 10));
+        assert_eq!(distances[&1], 0);
+        assert_eq!(distances[&9], 2);
+        assert_eq!(distances[&10], 3);
+        assert_eq!(distances.len(), 10);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(dijkstra(1, successors, |&n| n == 12), Some((vec![1, 2, 3, 6, 12], 12)));
+        assert_eq!(dijkstra(1, |&n: &u32| successors(&n).into_iter().filter(|&(n, _)| n < 5), |&n| n == 12), None);
+
+        let costs = dijkstra_all(1, |&n: &u32| successors(&n).into_iter().filter(|&(n, _)| n <= 8));
+        assert_eq!((costs[&1], costs[&4], costs[&8]), (0, 3, 7));
+        assert_eq!(costs.len(), 8);
+    }
+
+    #[test]
+    fn test_astar() {
+        let heuristic = |&n: &u32| 12u32.saturating_sub(n) / 6;
+        assert_eq!(astar(1, successors, heuristic, |&n| n == 12), Some((vec![1, 2, 3, 6, 12], 12)));
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse_str("S.#\n.##\n..E").unwrap();
+        let distances = grid_bfs(&grid, ORIGIN, |&c| c != b'#');
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&Point::new(2, 2)], 4);
+        assert!(!distances.contains_key(&Point::new(2, 0)));
+
+        let grid = Grid::parse_str("1199\n9119\n9911").unwrap();
+        let (path, cost) = grid_shortest_path(&grid, ORIGIN, Point::new(3, 2), |&c| Some((c - b'0') as u32)).unwrap();
+        assert_eq!(cost, 5);
+        assert_eq!(path.len(), 6);
+        let path = grid_shortest_path(&grid, ORIGIN, Point::new(3, 2), |&c| (c == b'1').then_some(1));
+        assert_eq!(path.map(|(_, cost)| cost), Some(5));
+        assert_eq!(grid_shortest_path(&grid, ORIGIN, Point::new(3, 0), |&c| (c == b'1').then_some(1)), None);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::util::grid::Grid;
+use crate::util::point::Point;
+use std::collections::HashMap;
+use std::ops::Index;
+
+/// Largest number of cells `SparseGrid::to_grid` will allocate
+pub const MAX_DENSE_CELLS: usize = 1 << 24;
+
+/// Grid of cells at arbitrary points, including negative ones, that only stores the cells that were set.
+/// The bounding box grows as cells are inserted.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the cells of a dense grid that match `keep`, at the same points
+    pub fn from_grid(grid: &Grid<T>, mut keep: impl FnMut(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let mut sparse = SparseGrid::new();
+        for y in 0..grid.height as i64 {
+            for x in 0..grid.width as i64 {
+                let point = Point::new(x, y);
+                if keep(&grid[point]) {
+                    sparse.insert(point, grid[point].clone());
+                }
+            }
+        }
+        sparse
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top left and bottom right corners of the smallest rectangle holding every cell, `None` if there are none
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Width of the bounding box
+    pub fn width(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// Height of the bounding box
+    pub fn height(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Sets the cell at `point`, returning its previous value
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+        });
+        self.cells.insert(point, value)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Cells in reading order: top to bottom, and left to right within a row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let mut cells = self.cells.iter().map(|(&point, value)| (point, value)).collect::<Vec<_>>();
+        cells.sort_unstable_by_key(|(point, _)| (point.y, point.x));
+        cells.into_iter()
+    }
+
+    /// Dense copy of the bounding box, with `fill` in the cells that were not set. The top left corner of the
+    /// bounding box becomes the origin. `None` if the bounding box has more than `MAX_DENSE_CELLS` cells.
+    pub fn to_grid(&self, fill: T) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.width(), self.height());
+        if width.checked_mul(height)? > MAX_DENSE_CELLS {
+            return None;
+        }
+
+        let mut grid = Grid::new(width, height, fill);
+        if let Some((min, _)) = self.bounds {
+            for (&point, value) in &self.cells {
+                grid[point - min] = value.clone();
+            }
+        }
+        Some(grid)
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut sparse = SparseGrid::new();
+        for (point, value) in iter {
+            sparse.insert(point, value);
+        }
+        sparse
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.cells[&index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut sparse = SparseGrid::new();
+        assert_eq!((sparse.bounds(), sparse.width(), sparse.height()), (None, 0, 0));
+
+        sparse.insert(Point::new(2, -1), 'a');
+        assert_eq!(sparse.bounds(), Some((Point::new(2, -1), Point::new(2, -1))));
+        sparse.insert(Point::new(-3, 4), 'b');
+        assert_eq!(sparse.insert(Point::new(2, -1), 'c'), Some('a'));
+        assert_eq!(sparse.bounds(), Some((Point::new(-3, -1), Point::new(2, 4))));
+        assert_eq!((sparse.width(), sparse.height(), sparse.len()), (6, 6, 2));
+        assert_eq!(sparse[Point::new(2, -1)], 'c');
+        assert_eq!(sparse.get(Point::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_reading_order() {
+        let sparse = [(Point::new(1, 1), 'd'), (Point::new(5, 0), 'b'), (Point::new(-1, 1), 'c'), (Point::new(9, -2), 'a')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+        assert_eq!(sparse.iter().map(|(_, &c)| c).collect::<String>(), "abcd");
+    }
+
+    #[test]
+    fn test_dense_conversion() {
+        let grid = Grid::parse_str("..#\n#..\n...").unwrap();
+        let sparse = SparseGrid::from_grid(&grid, |&c| c == b'#');
+        assert_eq!(sparse.iter().map(|(point, _)| point).collect::<Vec<_>>(), [Point::new(2, 0), Point::new(0, 1)]);
+        assert_eq!(sparse.to_grid(b'.').unwrap(), Grid::parse_str("..#\n#..").unwrap());
+
+        let sparse = [(Point::new(-5, -5), 1), (Point::new(-4, -3), 2)].into_iter().collect::<SparseGrid<_>>();
+        assert_eq!(sparse.to_grid(0).unwrap().data, [1, 0, 0, 0, 0, 2]);
+
+        let far = [(Point::new(0, 0), 1), (Point::new(1 << 20, 1 << 20), 2)].into_iter().collect::<SparseGrid<_>>();
+        assert_eq!(far.to_grid(0), None);
+        assert_eq!(SparseGrid::<u8>::new().to_grid(0).unwrap().data, []);
+    }
+}